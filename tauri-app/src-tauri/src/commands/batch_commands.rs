@@ -1,16 +1,71 @@
-use crate::services::{OCRService, GrammarService, ExportRecord, CSVExporterService};
+use crate::services::{
+    CacheEntry, CSVExporterService, ExportRecord, ExtractionCache, FileIntegrity, GrammarService,
+    IntegrityStatus, OCRService, PreprocessingOptions, TranslationService,
+};
+use crate::utils::file_extensions::SupportedExtensions;
 use anyhow::Result;
+use dashmap::DashMap;
+use futures::stream::{self, StreamExt};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::path::Path;
+use std::sync::Arc;
+use tauri::{AppHandle, Emitter, Manager};
 use tokio::sync::Mutex;
+use tokio_util::sync::CancellationToken;
+
+/// Batch runs this many files concurrently unless the caller overrides it
+/// via `batch_process_files`'s `max_concurrency` argument. Defaults to the
+/// number of available cores so multicore machines get a throughput win
+/// without the caller having to know its own hardware.
+fn default_max_concurrency() -> usize {
+    std::thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(4)
+}
+
+/// Reuses a small pool of `OCRService` instances across batch tasks instead
+/// of paying OCR client initialization cost per file. Sized organically: it
+/// grows to at most `max_concurrency` instances (one per in-flight task) and
+/// never allocates more, since a task always returns its instance when done.
+struct OcrPool {
+    idle: std::sync::Mutex<Vec<OCRService>>,
+}
+
+impl OcrPool {
+    fn new() -> Self {
+        Self {
+            idle: std::sync::Mutex::new(Vec::new()),
+        }
+    }
+
+    fn acquire(&self) -> Result<OCRService> {
+        if let Some(service) = self.idle.lock().unwrap().pop() {
+            return Ok(service);
+        }
+
+        OCRService::new().map_err(|e| anyhow::anyhow!("Failed to initialize OCR service: {}", e))
+    }
+
+    fn release(&self, service: OCRService) {
+        self.idle.lock().unwrap().push(service);
+    }
+}
 
 // Batch processing state
 pub struct BatchState {
     pub is_processing: bool,
     pub current_file_index: usize,
+    pub current_file_path: String,
     pub total_files: usize,
     pub completed_files: usize,
     pub failed_files: usize,
+    pub max_concurrency: usize,
     pub start_time: std::time::Instant,
+    /// Cancelled by `cancel_batch_processing` to interrupt in-flight OCR
+    /// requests immediately, rather than waiting for `is_processing` to be
+    /// polled between files.
+    pub cancellation_token: CancellationToken,
 }
 
 impl Default for BatchState {
@@ -18,10 +73,13 @@ impl Default for BatchState {
         Self {
             is_processing: false,
             current_file_index: 0,
+            current_file_path: String::new(),
             total_files: 0,
             completed_files: 0,
             failed_files: 0,
+            max_concurrency: default_max_concurrency(),
             start_time: std::time::Instant::now(),
+            cancellation_token: CancellationToken::new(),
         }
     }
 }
@@ -37,10 +95,13 @@ pub struct BatchProcessingResult {
     pub ocr_confidence: f32,
     pub processing_time: f64,
     pub error_message: Option<String>,
+    /// `target_lang` -> translated `corrected_text`, populated when
+    /// `batch_process_files` is called with a non-empty `target_langs`.
+    pub translations: BTreeMap<String, String>,
 }
 
 // Batch processing progress
-#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BatchProgress {
     pub is_processing: bool,
     pub current_file_index: usize,
@@ -52,6 +113,35 @@ pub struct BatchProgress {
     pub current_file_path: String,
 }
 
+/// Computes the same snapshot `get_batch_progress` returns, from an
+/// already-locked `BatchState` — shared by the polling command and the
+/// `batch-progress` event emitted after each file completes.
+fn build_batch_progress(state: &BatchState) -> BatchProgress {
+    let elapsed_seconds = state.start_time.elapsed().as_secs_f64();
+    let files_processed = state.completed_files + state.failed_files;
+
+    // Divide by `max_concurrency`: with N files in flight at once, the
+    // remaining files drain at roughly N times the single-file rate.
+    let estimated_remaining = if files_processed > 0 && state.total_files > files_processed {
+        let avg_time_per_file = elapsed_seconds / files_processed as f64;
+        let remaining_files = state.total_files - files_processed;
+        (avg_time_per_file * remaining_files as f64) / state.max_concurrency as f64
+    } else {
+        0.0
+    };
+
+    BatchProgress {
+        is_processing: state.is_processing,
+        current_file_index: state.current_file_index,
+        total_files: state.total_files,
+        completed_files: state.completed_files,
+        failed_files: state.failed_files,
+        elapsed_time_seconds: elapsed_seconds,
+        estimated_remaining_seconds: estimated_remaining,
+        current_file_path: state.current_file_path.clone(),
+    }
+}
+
 // Global batch state (in a real app, this should be managed better)
 type BatchStateType = Mutex<BatchState>;
 
@@ -59,52 +149,136 @@ type BatchStateType = Mutex<BatchState>;
 pub async fn batch_process_files(
     file_paths: Vec<String>,
     auto_correct: bool,
+    target_langs: Option<Vec<String>>,
+    max_concurrency: Option<usize>,
+    app_handle: AppHandle,
     batch_state: tauri::State<'_, BatchStateType>,
 ) -> Result<Vec<BatchProcessingResult>, String> {
     let mut state = batch_state.lock().await;
-    
+
     if state.is_processing {
         return Err("Batch processing is already in progress".to_string());
     }
-    
+
+    let concurrency = max_concurrency
+        .unwrap_or_else(default_max_concurrency)
+        .max(1)
+        .min(file_paths.len().max(1));
+
     state.is_processing = true;
     state.total_files = file_paths.len();
     state.current_file_index = 0;
+    state.current_file_path = String::new();
     state.completed_files = 0;
     state.failed_files = 0;
+    state.max_concurrency = concurrency;
     state.start_time = std::time::Instant::now();
+    state.cancellation_token = CancellationToken::new();
+    let cancellation_token = state.cancellation_token.clone();
     drop(state);
-    
-    let mut results = Vec::new();
-    
-    for (index, file_path) in file_paths.iter().enumerate() {
-        // Update current file index
-        {
-            let mut state = batch_state.lock().await;
-            state.current_file_index = index;
-        }
-        
-        let result = process_single_file_batch(file_path, auto_correct).await;
-        
-        // Update counters
-        {
-            let mut state = batch_state.lock().await;
-            if result.success {
-                state.completed_files += 1;
-            } else {
-                state.failed_files += 1;
+
+    // Grammar checking has no mutable state of its own (its cache is an
+    // `Arc<DashMap>`), so a single shared instance can run all tasks
+    // concurrently. OCR needs one `OCRService` per in-flight task since
+    // `extract_text_from_image`/`extract_text_from_video` take `&mut self`.
+    let grammar_service = Arc::new(GrammarService::new());
+    let ocr_pool = Arc::new(OcrPool::new());
+    let total_files = file_paths.len();
+
+    let app_data_dir = app_handle.path().app_data_dir().ok();
+    let extraction_cache = app_data_dir.as_deref().and_then(|dir| ExtractionCache::new(dir).ok());
+    let cache_entries = Arc::new(
+        extraction_cache
+            .as_ref()
+            .map(ExtractionCache::load)
+            .unwrap_or_default(),
+    );
+
+    let results = stream::iter(file_paths.into_iter().enumerate())
+        .map(|(index, file_path)| {
+            let grammar_service = grammar_service.clone();
+            let ocr_pool = ocr_pool.clone();
+            let target_langs = target_langs.clone();
+            let app_handle = app_handle.clone();
+            let cancellation_token = cancellation_token.clone();
+            let cache_entries = cache_entries.clone();
+            let batch_state = &batch_state;
+
+            async move {
+                // Check for cancellation before starting each file so a
+                // mid-flight `cancel_batch_processing` keeps new files from
+                // starting (files already in progress are interrupted through
+                // the same cancellation token), and mark any files we never
+                // started as skipped rather than silently dropping them.
+                if !batch_state.lock().await.is_processing || cancellation_token.is_cancelled() {
+                    return BatchProcessingResult {
+                        file_path,
+                        success: false,
+                        original_text: String::new(),
+                        corrected_text: String::new(),
+                        grammar_error_count: 0,
+                        ocr_confidence: 0.0,
+                        processing_time: 0.0,
+                        error_message: Some("Skipped: batch cancelled by user".to_string()),
+                        translations: BTreeMap::new(),
+                    };
+                }
+
+                {
+                    let mut state = batch_state.lock().await;
+                    state.current_file_index = index;
+                    state.current_file_path = file_path.clone();
+                }
+
+                let result = process_single_file_batch(
+                    &file_path,
+                    auto_correct,
+                    target_langs.as_deref(),
+                    &grammar_service,
+                    &ocr_pool,
+                    &cancellation_token,
+                    &cache_entries,
+                )
+                .await;
+
+                {
+                    let mut state = batch_state.lock().await;
+                    if result.success {
+                        state.completed_files += 1;
+                    } else {
+                        state.failed_files += 1;
+                    }
+
+                    let _ = app_handle.emit("batch-progress", build_batch_progress(&state));
+                }
+
+                result
             }
+        })
+        .buffer_unordered(concurrency)
+        .collect::<Vec<_>>()
+        .await;
+
+    log::info!(
+        "Batch processing finished: {} of {} files produced a result",
+        results.len(),
+        total_files
+    );
+
+    if let Some(cache) = extraction_cache.as_ref() {
+        if let Err(e) = cache.save(&cache_entries) {
+            log::warn!("Failed to persist extraction cache: {}", e);
         }
-        
-        results.push(result);
     }
-    
+
     // Mark processing as complete
     {
         let mut state = batch_state.lock().await;
         state.is_processing = false;
     }
-    
+
+    let _ = app_handle.emit("batch-complete", compute_batch_statistics(&results));
+
     Ok(results)
 }
 
@@ -113,28 +287,7 @@ pub async fn get_batch_progress(
     batch_state: tauri::State<'_, BatchStateType>,
 ) -> Result<BatchProgress, String> {
     let state = batch_state.lock().await;
-    
-    let elapsed_seconds = state.start_time.elapsed().as_secs_f64();
-    let files_processed = state.completed_files + state.failed_files;
-    
-    let estimated_remaining = if files_processed > 0 && state.total_files > files_processed {
-        let avg_time_per_file = elapsed_seconds / files_processed as f64;
-        let remaining_files = state.total_files - files_processed;
-        avg_time_per_file * remaining_files as f64
-    } else {
-        0.0
-    };
-    
-    Ok(BatchProgress {
-        is_processing: state.is_processing,
-        current_file_index: state.current_file_index,
-        total_files: state.total_files,
-        completed_files: state.completed_files,
-        failed_files: state.failed_files,
-        elapsed_time_seconds: elapsed_seconds,
-        estimated_remaining_seconds: estimated_remaining,
-        current_file_path: String::new(), // Would need to track this separately
-    })
+    Ok(build_batch_progress(&state))
 }
 
 #[tauri::command]
@@ -143,6 +296,7 @@ pub async fn cancel_batch_processing(
 ) -> Result<(), String> {
     let mut state = batch_state.lock().await;
     state.is_processing = false;
+    state.cancellation_token.cancel();
     Ok(())
 }
 
@@ -165,12 +319,17 @@ pub async fn batch_export_results(
             processing_time: r.processing_time,
             source_type: "Batch".to_string(),
             error_summary: r.error_message.unwrap_or_default(),
+            translations: r
+                .translations
+                .into_iter()
+                .map(|(lang, text)| (CSVExporterService::translation_column(&lang), text))
+                .collect(),
         })
         .collect();
-    
+
     CSVExporterService::export_multiple_records(&export_path, &export_records, None)
         .map_err(|e| format!("Export failed: {}", e))?;
-    
+
     Ok(format!("Exported {} records to {}", export_records.len(), export_path))
 }
 
@@ -178,24 +337,30 @@ pub async fn batch_export_results(
 pub async fn get_batch_statistics(
     results: Vec<BatchProcessingResult>,
 ) -> Result<serde_json::Value, String> {
+    Ok(compute_batch_statistics(&results))
+}
+
+/// Shared by `get_batch_statistics` and the terminal `batch-complete` event
+/// so both report the exact same numbers for a finished run.
+fn compute_batch_statistics(results: &[BatchProcessingResult]) -> serde_json::Value {
     let total_files = results.len();
     let successful_files = results.iter().filter(|r| r.success).count();
     let failed_files = total_files - successful_files;
-    
+
     let total_processing_time: f64 = results.iter().map(|r| r.processing_time).sum();
     let avg_processing_time = if total_files > 0 {
         total_processing_time / total_files as f64
     } else {
         0.0
     };
-    
+
     let total_words: usize = results
         .iter()
         .map(|r| r.original_text.split_whitespace().count())
         .sum();
-    
+
     let total_errors: usize = results.iter().map(|r| r.grammar_error_count).sum();
-    
+
     let avg_confidence: f64 = if successful_files > 0 {
         results
             .iter()
@@ -206,8 +371,8 @@ pub async fn get_batch_statistics(
     } else {
         0.0
     };
-    
-    let stats = serde_json::json!({
+
+    serde_json::json!({
         "total_files": total_files,
         "successful_files": successful_files,
         "failed_files": failed_files,
@@ -217,14 +382,20 @@ pub async fn get_batch_statistics(
         "total_words": total_words,
         "total_errors": total_errors,
         "avg_confidence": avg_confidence,
-    });
-    
-    Ok(stats)
+    })
 }
 
-async fn process_single_file_batch(file_path: &str, auto_correct: bool) -> BatchProcessingResult {
+async fn process_single_file_batch(
+    file_path: &str,
+    auto_correct: bool,
+    target_langs: Option<&[String]>,
+    grammar_service: &GrammarService,
+    ocr_pool: &OcrPool,
+    cancellation_token: &CancellationToken,
+    cache_entries: &DashMap<String, CacheEntry>,
+) -> BatchProcessingResult {
     let start_time = std::time::Instant::now();
-    
+
     // Validate file exists
     if !Path::new(file_path).exists() {
         return BatchProcessingResult {
@@ -236,12 +407,72 @@ async fn process_single_file_batch(file_path: &str, auto_correct: bool) -> Batch
             ocr_confidence: 0.0,
             processing_time: start_time.elapsed().as_secs_f64(),
             error_message: Some("File not found".to_string()),
+            translations: BTreeMap::new(),
+        };
+    }
+
+    // Skip OCR/grammar entirely if the file hasn't changed since it was
+    // last cached (same size and modified date).
+    let fingerprint = ExtractionCache::fingerprint(file_path).ok();
+    if let Some((size, modified_date)) = fingerprint {
+        if let Some(cached) = cache_entries.get(file_path) {
+            if cached.size == size && cached.modified_date == modified_date {
+                let translations =
+                    translate_corrected_text(&cached.corrected_text, target_langs).await;
+
+                return BatchProcessingResult {
+                    file_path: file_path.to_string(),
+                    success: true,
+                    original_text: cached.original_text.clone(),
+                    corrected_text: cached.corrected_text.clone(),
+                    grammar_error_count: cached.grammar_error_count,
+                    ocr_confidence: cached.ocr_confidence,
+                    processing_time: start_time.elapsed().as_secs_f64(),
+                    error_message: None,
+                    translations,
+                };
+            }
+        }
+    }
+
+    // Pre-screen for structural corruption before spending an OCR round-trip
+    // on a file that was never going to decode.
+    if let IntegrityStatus::Broken { reason } = FileIntegrity::check(file_path) {
+        return BatchProcessingResult {
+            file_path: file_path.to_string(),
+            success: false,
+            original_text: String::new(),
+            corrected_text: String::new(),
+            grammar_error_count: 0,
+            ocr_confidence: 0.0,
+            processing_time: start_time.elapsed().as_secs_f64(),
+            error_message: Some(reason),
+            translations: BTreeMap::new(),
         };
     }
-    
+
     // Try to process the file
-    match process_file_internal(file_path, auto_correct).await {
+    match process_file_internal(file_path, auto_correct, grammar_service, ocr_pool, cancellation_token)
+        .await
+    {
         Ok((original_text, corrected_text, error_count, confidence)) => {
+            let translations = translate_corrected_text(&corrected_text, target_langs).await;
+
+            if let Some((size, modified_date)) = fingerprint {
+                cache_entries.insert(
+                    file_path.to_string(),
+                    CacheEntry {
+                        path: file_path.to_string(),
+                        size,
+                        modified_date,
+                        original_text: original_text.clone(),
+                        corrected_text: corrected_text.clone(),
+                        grammar_error_count: error_count,
+                        ocr_confidence: confidence,
+                    },
+                );
+            }
+
             BatchProcessingResult {
                 file_path: file_path.to_string(),
                 success: true,
@@ -251,19 +482,44 @@ async fn process_single_file_batch(file_path: &str, auto_correct: bool) -> Batch
                 ocr_confidence: confidence,
                 processing_time: start_time.elapsed().as_secs_f64(),
                 error_message: None,
+                translations,
             }
         }
+        Err(e) => BatchProcessingResult {
+            file_path: file_path.to_string(),
+            success: false,
+            original_text: String::new(),
+            corrected_text: String::new(),
+            grammar_error_count: 0,
+            ocr_confidence: 0.0,
+            processing_time: start_time.elapsed().as_secs_f64(),
+            error_message: Some(e.to_string()),
+            translations: BTreeMap::new(),
+        },
+    }
+}
+
+/// Translates `corrected_text` into each of `target_langs`, logging and
+/// skipping on failure rather than failing the whole batch item — a single
+/// unavailable translation backend shouldn't discard an otherwise-successful
+/// OCR/grammar result.
+async fn translate_corrected_text(
+    corrected_text: &str,
+    target_langs: Option<&[String]>,
+) -> BTreeMap<String, String> {
+    let Some(target_langs) = target_langs.filter(|langs| !langs.is_empty()) else {
+        return BTreeMap::new();
+    };
+
+    let translation_service = TranslationService::new();
+    match translation_service
+        .translate_to_many(corrected_text, "auto", target_langs, Some(2000))
+        .await
+    {
+        Ok(translations) => translations.into_iter().collect(),
         Err(e) => {
-            BatchProcessingResult {
-                file_path: file_path.to_string(),
-                success: false,
-                original_text: String::new(),
-                corrected_text: String::new(),
-                grammar_error_count: 0,
-                ocr_confidence: 0.0,
-                processing_time: start_time.elapsed().as_secs_f64(),
-                error_message: Some(e.to_string()),
-            }
+            log::warn!("Failed to translate batch result: {}", e);
+            BTreeMap::new()
         }
     }
 }
@@ -271,24 +527,51 @@ async fn process_single_file_batch(file_path: &str, auto_correct: bool) -> Batch
 async fn process_file_internal(
     file_path: &str,
     auto_correct: bool,
+    grammar_service: &GrammarService,
+    ocr_pool: &OcrPool,
+    cancellation_token: &CancellationToken,
 ) -> Result<(String, String, usize, f32)> {
-    // This is a simplified version - in reality, you'd use the actual OCR and Grammar services
-    // For now, just return placeholder data
-    
-    // In a real implementation, you would:
-    // 1. Use OCRService to extract text from the file
-    // 2. Use GrammarService to check and correct the text
-    // 3. Return the actual results
-    
-    // Placeholder implementation
-    let original_text = format!("Extracted text from {}", file_path);
-    let corrected_text = if auto_correct {
-        format!("Corrected text from {}", file_path)
-    } else {
-        original_text.clone()
+    // Borrow an OCRService from the pool instead of constructing one per
+    // file; its extraction methods take `&mut self` so only one task holds
+    // it at a time, and it's returned to the pool for the next task even on
+    // a cancelled/failed run.
+    let mut ocr_service = ocr_pool.acquire()?;
+
+    let preprocessing = Some(PreprocessingOptions::default());
+
+    // Race the OCR call against cancellation so a cancel aborts the current
+    // HTTP request to the Python service instead of waiting out its timeout.
+    let ocr_result = tokio::select! {
+        _ = cancellation_token.cancelled() => {
+            ocr_pool.release(ocr_service);
+            return Err(anyhow::anyhow!("Batch cancelled during OCR"));
+        }
+        result = async {
+            if SupportedExtensions::is_video(file_path) {
+                ocr_service.extract_text_from_video(file_path, preprocessing).await
+            } else {
+                ocr_service.extract_text_from_image(file_path, preprocessing).await
+            }
+        } => {
+            let result = result.map_err(|e| anyhow::anyhow!("OCR failed: {}", e));
+            ocr_pool.release(ocr_service);
+            result?
+        },
+    };
+
+    let grammar_result = tokio::select! {
+        _ = cancellation_token.cancelled() => {
+            return Err(anyhow::anyhow!("Batch cancelled during grammar check"));
+        }
+        result = grammar_service.check_text(&ocr_result.text, auto_correct) => {
+            result.map_err(|e| anyhow::anyhow!("Grammar check failed: {}", e))?
+        }
     };
-    let error_count = if auto_correct { 2 } else { 0 };
-    let confidence = 0.95;
-    
-    Ok((original_text, corrected_text, error_count, confidence))
+
+    Ok((
+        ocr_result.text,
+        grammar_result.corrected_text,
+        grammar_result.error_count,
+        ocr_result.confidence,
+    ))
 }
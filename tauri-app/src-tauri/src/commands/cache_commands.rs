@@ -0,0 +1,68 @@
+use crate::services::{ExportRecord, ResultCache, ResultCacheEntry, ResultCacheProgress};
+use dashmap::DashMap;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tauri::{AppHandle, Emitter};
+
+/// Shared in-memory view of the result cache, loaded once at startup and
+/// kept up to date as batch/export commands store fresh results into it.
+pub struct ResultCacheState(pub Arc<DashMap<String, ResultCacheEntry>>);
+
+/// Wraps the on-disk handle so the `close-requested` window event can write
+/// `ResultCacheState` back out without re-deriving the cache directory.
+pub struct ResultCacheHandle(pub ResultCache);
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct CachedLookupResult {
+    pub hits: HashMap<String, ExportRecord>,
+    pub misses: Vec<String>,
+}
+
+/// Checks `file_paths` against the result cache for `ocr_engine`, emitting a
+/// `result-cache-progress` event per file so the UI can show how many files
+/// were skipped before a batch run's OCR work even starts.
+#[tauri::command]
+pub async fn check_result_cache(
+    file_paths: Vec<String>,
+    ocr_engine: String,
+    app_handle: AppHandle,
+    cache_state: tauri::State<'_, ResultCacheState>,
+) -> Result<CachedLookupResult, String> {
+    let files_to_check = file_paths.len();
+    let mut hits = HashMap::new();
+    let mut misses = Vec::new();
+
+    for (index, file_path) in file_paths.into_iter().enumerate() {
+        match ResultCache::get(&cache_state.0, &file_path, &ocr_engine) {
+            Some(record) => {
+                hits.insert(file_path, record);
+            }
+            None => misses.push(file_path),
+        }
+
+        let _ = app_handle.emit(
+            "result-cache-progress",
+            ResultCacheProgress {
+                files_checked: index + 1,
+                files_to_check,
+                cache_hits: hits.len(),
+            },
+        );
+    }
+
+    Ok(CachedLookupResult { hits, misses })
+}
+
+/// Stores a freshly produced OCR result in the in-memory result cache. The
+/// cache is only persisted to disk on shutdown (see `lib.rs`'s
+/// `close-requested` handler), so this just updates the shared map.
+#[tauri::command]
+pub async fn store_cached_result(
+    file_path: String,
+    ocr_engine: String,
+    record: ExportRecord,
+    cache_state: tauri::State<'_, ResultCacheState>,
+) -> Result<(), String> {
+    ResultCache::insert(&cache_state.0, &file_path, &ocr_engine, record);
+    Ok(())
+}
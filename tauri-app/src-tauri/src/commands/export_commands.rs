@@ -0,0 +1,128 @@
+use crate::services::{
+    CSVExporterService, CsvExporter, CsvImportSummary, ExportOptions, ExportRecord, ExportStatistics,
+    Exporter, JsonLinesExporter, XlsxExporter,
+};
+use std::path::Path;
+use tauri::{AppHandle, Emitter};
+
+/// Progress emitted by `get_csv_statistics` while it scans a large export
+/// log, so the UI can show a bar instead of blocking silently.
+#[derive(Clone, serde::Serialize)]
+struct ExportStatisticsProgress {
+    records_scanned: usize,
+    total_records: usize,
+}
+
+/// Picks the `Exporter` implementation: an explicit `format` wins, otherwise
+/// it's inferred from `file_path`'s extension, falling back to CSV.
+fn resolve_exporter(format: Option<&str>, file_path: &str) -> Box<dyn Exporter> {
+    let format = format
+        .map(str::to_lowercase)
+        .or_else(|| {
+            Path::new(file_path)
+                .extension()
+                .and_then(|ext| ext.to_str())
+                .map(str::to_lowercase)
+        })
+        .unwrap_or_else(|| "csv".to_string());
+
+    match format.as_str() {
+        "jsonl" | "ndjson" | "jsonlines" => Box::new(JsonLinesExporter),
+        "xlsx" | "excel" => Box::new(XlsxExporter),
+        _ => Box::new(CsvExporter),
+    }
+}
+
+#[tauri::command]
+pub async fn export_to_csv(
+    file_path: String,
+    record: ExportRecord,
+    options: Option<ExportOptions>,
+    format: Option<String>,
+) -> Result<(), String> {
+    resolve_exporter(format.as_deref(), &file_path)
+        .write_record(&file_path, &record, options)
+        .map_err(|e| format!("Failed to export record: {}", e))
+}
+
+#[tauri::command]
+pub async fn export_multiple_to_csv(
+    file_path: String,
+    records: Vec<ExportRecord>,
+    options: Option<ExportOptions>,
+    format: Option<String>,
+) -> Result<(), String> {
+    resolve_exporter(format.as_deref(), &file_path)
+        .write_batch(&file_path, &records, options)
+        .map_err(|e| format!("Failed to export records: {}", e))
+}
+
+#[tauri::command]
+pub async fn read_csv_file(file_path: String, format: Option<String>) -> Result<Vec<ExportRecord>, String> {
+    resolve_exporter(format.as_deref(), &file_path)
+        .read_records(&file_path)
+        .map_err(|e| format!("Failed to read export file: {}", e))
+}
+
+#[tauri::command]
+pub async fn get_csv_statistics(
+    file_path: String,
+    format: Option<String>,
+    app_handle: AppHandle,
+) -> Result<ExportStatistics, String> {
+    let on_progress = |records_scanned: usize, total_records: usize| {
+        let _ = app_handle.emit(
+            "export-statistics-progress",
+            ExportStatisticsProgress { records_scanned, total_records },
+        );
+    };
+
+    resolve_exporter(format.as_deref(), &file_path)
+        .statistics(&file_path, Some(&on_progress))
+        .map_err(|e| format!("Failed to compute export statistics: {}", e))
+}
+
+/// A chunk of rows streamed back by `import_csv_resilient` as it reads a
+/// large export log in, so the frontend renders incrementally instead of
+/// waiting on (and the IPC channel carrying) the whole file in one payload.
+#[derive(Clone, serde::Serialize)]
+struct CsvImportBatch {
+    records: Vec<ExportRecord>,
+    records_read_so_far: usize,
+}
+
+/// How many records `import_csv_resilient` buffers before flushing a
+/// `csv-import-batch` event, bounding how much a single IPC payload holds.
+const CSV_IMPORT_BATCH_SIZE: usize = 500;
+
+/// Imports a CSV export log that may be too large or too corrupted for
+/// `read_csv_file`: invalid UTF-8 is replaced rather than rejected, and
+/// malformed rows are skipped and counted instead of aborting the import.
+/// Rows are streamed back via `csv-import-batch` events of up to
+/// `CSV_IMPORT_BATCH_SIZE` records -- `read_csv_file_streaming`'s
+/// per-record callback is what makes that possible; collecting its output
+/// into one `Vec<ExportRecord>` here would recreate the in-memory/IPC
+/// blowup it exists to avoid on a multi-hundred-MB export log.
+#[tauri::command]
+pub async fn import_csv_resilient(file_path: String, app_handle: AppHandle) -> Result<CsvImportSummary, String> {
+    let mut pending = Vec::with_capacity(CSV_IMPORT_BATCH_SIZE);
+    let mut records_read_so_far = 0usize;
+
+    let summary = CSVExporterService::read_csv_file_streaming(&file_path, |record| {
+        pending.push(record);
+        records_read_so_far += 1;
+        if pending.len() >= CSV_IMPORT_BATCH_SIZE {
+            let _ = app_handle.emit(
+                "csv-import-batch",
+                CsvImportBatch { records: std::mem::take(&mut pending), records_read_so_far },
+            );
+        }
+    })
+    .map_err(|e| format!("Failed to import CSV file: {}", e))?;
+
+    if !pending.is_empty() {
+        let _ = app_handle.emit("csv-import-batch", CsvImportBatch { records: pending, records_read_so_far });
+    }
+
+    Ok(summary)
+}
@@ -1,4 +1,4 @@
-use crate::services::{FileHandlerService, FileInfo};
+use crate::services::{ExtractedFrame, FileHandlerService, FileInfo, FrameExtractionBackend, MediaMetadata};
 use anyhow::Result;
 
 #[tauri::command]
@@ -7,6 +7,12 @@ pub async fn get_file_info(file_path: String) -> Result<FileInfo, String> {
         .map_err(|e| format!("Failed to get file info: {}", e))
 }
 
+#[tauri::command]
+pub async fn get_media_metadata(file_path: String) -> Result<MediaMetadata, String> {
+    FileHandlerService::get_media_metadata(&file_path)
+        .map_err(|e| format!("Failed to get media metadata: {}", e))
+}
+
 #[tauri::command]
 pub async fn validate_file_path(file_path: String) -> Result<bool, String> {
     match FileHandlerService::validate_file_path(&file_path) {
@@ -83,11 +89,19 @@ pub async fn extract_frames_from_video(
     video_path: String,
     output_dir: String,
     frame_interval: Option<u32>,
-) -> Result<Vec<String>, String> {
-    FileHandlerService::extract_frames_from_video(&video_path, &output_dir, frame_interval)
+    backend: Option<FrameExtractionBackend>,
+) -> Result<Vec<ExtractedFrame>, String> {
+    FileHandlerService::extract_frames_from_video(&video_path, &output_dir, frame_interval, backend)
+        .await
         .map_err(|e| format!("Failed to extract frames from video: {}", e))
 }
 
+#[tauri::command]
+pub async fn extract_archive_images(archive_path: String, output_dir: String) -> Result<Vec<String>, String> {
+    FileHandlerService::extract_archive_images(&archive_path, &output_dir)
+        .map_err(|e| format!("Failed to extract images from archive: {}", e))
+}
+
 #[tauri::command]
 pub async fn cleanup_temp_files(temp_dir: String) -> Result<(), String> {
     FileHandlerService::cleanup_temp_files(&temp_dir)
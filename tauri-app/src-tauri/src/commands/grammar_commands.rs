@@ -1,7 +1,9 @@
 use crate::services::{GrammarService, GrammarCheckResult, LanguageStats, GrammarConfig};
-use crate::error::ToTauriResult;
+use crate::services::rule_packs::{RulePackInfo, RulePackManager, RulePackSource};
+use crate::services::grammar_plugins::{GrammarPluginInfo, GrammarPluginManager};
+use crate::error::{AppErrorResponse, ToTauriResult};
 use tokio::sync::Mutex;
-use tauri::State;
+use tauri::{AppHandle, Manager, State};
 
 
 pub struct GrammarState(pub Mutex<GrammarService>);
@@ -11,12 +13,12 @@ pub async fn check_grammar(
     text: String,
     auto_correct: bool,
     state: State<'_, GrammarState>,
-) -> Result<GrammarCheckResult, String> {
+) -> Result<GrammarCheckResult, AppErrorResponse> {
     let grammar_service = state.0.lock().await;
     grammar_service
         .check_text(&text, auto_correct)
         .await
-        .to_tauri_result()
+        .to_tauri_response()
 }
 
 #[tauri::command]
@@ -32,6 +34,43 @@ pub async fn apply_specific_corrections(
         .map_err(|e| format!("Failed to apply corrections: {}", e))
 }
 
+#[tauri::command]
+pub async fn check_grammar_lazy(
+    text: String,
+    state: State<'_, GrammarState>,
+) -> Result<GrammarCheckResult, String> {
+    let grammar_service = state.0.lock().await;
+    grammar_service
+        .check_text_lazy(&text)
+        .await
+        .to_tauri_result()
+}
+
+#[tauri::command]
+pub async fn check_grammar_incremental(
+    text: String,
+    state: State<'_, GrammarState>,
+) -> Result<GrammarCheckResult, AppErrorResponse> {
+    let grammar_service = state.0.lock().await;
+    grammar_service
+        .check_text_incremental(&text)
+        .await
+        .to_tauri_response()
+}
+
+#[tauri::command]
+pub async fn resolve_correction(
+    text: String,
+    error_index: usize,
+    state: State<'_, GrammarState>,
+) -> Result<Vec<String>, String> {
+    let grammar_service = state.0.lock().await;
+    grammar_service
+        .resolve_correction(&text, error_index)
+        .await
+        .to_tauri_result()
+}
+
 #[tauri::command]
 pub async fn get_language_statistics(
     text: String,
@@ -71,12 +110,21 @@ pub async fn set_grammar_config(
 }
 
 #[tauri::command]
-pub async fn get_grammar_providers() -> Result<Vec<String>, String> {
-    Ok(vec![
+pub async fn get_grammar_providers(app_handle: AppHandle) -> Result<Vec<String>, String> {
+    let mut providers = vec![
         "Harper".to_string(),
         "OfflineRules".to_string(),
-        "Hybrid".to_string(),
-    ])
+        "Plugins".to_string(),
+        "LanguageTool".to_string(),
+    ];
+
+    if let Ok(app_data_dir) = app_handle.path().app_data_dir() {
+        if let Ok(manager) = GrammarPluginManager::new(&app_data_dir) {
+            providers.extend(manager.list_plugins().into_iter().map(|p| p.name));
+        }
+    }
+
+    Ok(providers)
 }
 
 #[tauri::command]
@@ -95,6 +143,76 @@ pub async fn get_supported_languages() -> Result<Vec<String>, String> {
     ])
 }
 
+#[tauri::command]
+pub async fn install_rule_pack(
+    name: String,
+    source: RulePackSource,
+    app_handle: AppHandle,
+) -> Result<RulePackInfo, String> {
+    let app_data_dir = app_handle
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to resolve app data directory: {}", e))?;
+
+    let manager = RulePackManager::new(&app_data_dir).to_tauri_result()?;
+    manager.install_rule_pack(&name, source).to_tauri_result()
+}
+
+#[tauri::command]
+pub async fn list_rule_packs(app_handle: AppHandle) -> Result<Vec<RulePackInfo>, String> {
+    let app_data_dir = app_handle
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to resolve app data directory: {}", e))?;
+
+    let manager = RulePackManager::new(&app_data_dir).to_tauri_result()?;
+    Ok(manager.list_rule_packs())
+}
+
+#[tauri::command]
+pub async fn remove_rule_pack(name: String, app_handle: AppHandle) -> Result<(), String> {
+    let app_data_dir = app_handle
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to resolve app data directory: {}", e))?;
+
+    let manager = RulePackManager::new(&app_data_dir).to_tauri_result()?;
+    manager.remove_rule_pack(&name).to_tauri_result()
+}
+
+#[tauri::command]
+pub async fn install_grammar_plugin(path: String, app_handle: AppHandle) -> Result<GrammarPluginInfo, String> {
+    let app_data_dir = app_handle
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to resolve app data directory: {}", e))?;
+
+    let manager = GrammarPluginManager::new(&app_data_dir).to_tauri_result()?;
+    manager.install_plugin(&path).to_tauri_result()
+}
+
+#[tauri::command]
+pub async fn list_grammar_plugins(app_handle: AppHandle) -> Result<Vec<GrammarPluginInfo>, String> {
+    let app_data_dir = app_handle
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to resolve app data directory: {}", e))?;
+
+    let manager = GrammarPluginManager::new(&app_data_dir).to_tauri_result()?;
+    Ok(manager.list_plugins())
+}
+
+#[tauri::command]
+pub async fn remove_grammar_plugin(name: String, app_handle: AppHandle) -> Result<(), String> {
+    let app_data_dir = app_handle
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to resolve app data directory: {}", e))?;
+
+    let manager = GrammarPluginManager::new(&app_data_dir).to_tauri_result()?;
+    manager.remove_plugin(&name).to_tauri_result()
+}
+
 #[tauri::command]
 pub async fn smart_grammar_check(
     text: String,
@@ -3,9 +3,15 @@ pub mod grammar_commands;
 pub mod file_commands;
 pub mod export_commands;
 pub mod batch_commands;
+pub mod translation_commands;
+pub mod cache_commands;
+pub mod plugin_commands;
 
 pub use ocr_commands::*;
 pub use grammar_commands::*;
 pub use file_commands::*;
 pub use export_commands::*;
 pub use batch_commands::*;
+pub use translation_commands::*;
+pub use cache_commands::*;
+pub use plugin_commands::*;
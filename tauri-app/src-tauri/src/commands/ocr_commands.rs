@@ -34,7 +34,16 @@ pub async fn get_preprocessing_preview(
 #[tauri::command]
 pub async fn validate_image_file(file_path: String) -> Result<bool, String> {
     match file_validation::validate_file_path(&file_path) {
-        Ok(_) => Ok(SupportedExtensions::is_image(&file_path)),
+        Ok(_) => {
+            if !SupportedExtensions::is_image(&file_path) {
+                return Ok(false);
+            }
+
+            // Content must actually match the extension, so a renamed file
+            // can't sail through into the OCR pipeline as the wrong format.
+            file_validation::validate_format_matches_extension(&file_path).to_tauri_result()?;
+            Ok(true)
+        }
         Err(e) => Err(e.to_tauri_error()),
     }
 }
@@ -70,10 +79,11 @@ pub async fn extract_video_frames(
     video_path: String,
     output_dir: String,
     frame_interval: Option<u32>,
-) -> Result<Vec<String>, String> {
+    backend: Option<crate::services::FrameExtractionBackend>,
+) -> Result<Vec<crate::services::ExtractedFrame>, String> {
     use crate::services::FileHandlerService;
 
-    FileHandlerService::extract_frames_from_video(&video_path, &output_dir, frame_interval)
+    FileHandlerService::extract_frames_from_video(&video_path, &output_dir, frame_interval, backend)
         .await
         .to_tauri_result()
 }
@@ -0,0 +1,151 @@
+use crate::services::{ExternalPlugin, ExternalPluginInfo, ExternalPluginManager};
+use serde_json::Value;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Wall-clock ceiling on a single external-plugin JSON-RPC call. `call()`'s
+/// blocking write/read has no I/O timeout of its own, so a plugin that
+/// accepts a request and never replies is bounded here instead -- past this,
+/// `call_external_plugin` gives up and reports failure rather than hanging
+/// the Tokio worker it runs on forever.
+const EXTERNAL_PLUGIN_CALL_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Live registry of spawned external plugin processes, populated at startup
+/// from `ExternalPluginManager`'s manifest and updated as plugins are
+/// installed/removed at runtime, so a newly installed plugin's commands
+/// become callable without an app restart.
+pub struct ExternalPluginRegistry {
+    manager: ExternalPluginManager,
+    plugins: Mutex<Vec<Arc<ExternalPlugin>>>,
+}
+
+impl ExternalPluginRegistry {
+    pub fn new(manager: ExternalPluginManager) -> Self {
+        let plugins = manager.spawn_all().into_iter().map(Arc::new).collect();
+        Self {
+            manager,
+            plugins: Mutex::new(plugins),
+        }
+    }
+
+    fn find(&self, plugin_name: &str) -> Option<Arc<ExternalPlugin>> {
+        self.plugins
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|p| p.name() == plugin_name)
+            .cloned()
+    }
+}
+
+/// Spawns `info.binary_path` and swaps the result into `registry.plugins` in
+/// place of any existing entry with the same name. A failed respawn is
+/// logged, not propagated -- the caller already has its own outcome to
+/// report (an install result, or a timed-out call) and a plugin that won't
+/// come back up just stays absent from the registry until the user
+/// reinstalls it.
+fn respawn_into_registry(registry: &ExternalPluginRegistry, info: &ExternalPluginInfo, context: &str) {
+    match ExternalPlugin::spawn(&info.name, Path::new(&info.binary_path)) {
+        Ok(plugin) => {
+            let mut plugins = registry.plugins.lock().unwrap();
+            plugins.retain(|p| p.name() != info.name);
+            plugins.push(Arc::new(plugin));
+        }
+        Err(e) => log::warn!("{} '{}' but failed to spawn it: {}", context, info.name, e),
+    }
+}
+
+/// Kills a plugin whose call ran past `EXTERNAL_PLUGIN_CALL_TIMEOUT` and
+/// respawns it from the manifest. The outer `tokio::time::timeout` around
+/// `call()` only stops the caller from waiting on it -- it can't cancel the
+/// blocking read the `spawn_blocking` thread is stuck in, so without this
+/// the `stdin`/`stdout` mutexes that call still holds stay wedged forever
+/// and every later call to the same plugin deadlocks on them.
+fn restart_hung_plugin(registry: &ExternalPluginRegistry, plugin_name: &str) {
+    if let Some(plugin) = registry.find(plugin_name) {
+        plugin.kill();
+    }
+
+    if let Some(info) = registry.manager.list_plugins().into_iter().find(|p| p.name == plugin_name) {
+        respawn_into_registry(registry, &info, "Restarted hung plugin");
+    }
+}
+
+#[tauri::command]
+pub async fn install_external_plugin(
+    source_path: String,
+    registry: tauri::State<'_, ExternalPluginRegistry>,
+) -> Result<ExternalPluginInfo, String> {
+    let info = registry
+        .manager
+        .install_plugin(&source_path)
+        .map_err(|e| format!("Failed to install plugin: {}", e))?;
+
+    // Respawn so the new/updated plugin is immediately callable instead of
+    // only taking effect on next app launch.
+    respawn_into_registry(&registry, &info, "Installed plugin");
+
+    Ok(info)
+}
+
+#[tauri::command]
+pub async fn list_external_plugins(
+    registry: tauri::State<'_, ExternalPluginRegistry>,
+) -> Result<Vec<ExternalPluginInfo>, String> {
+    Ok(registry.manager.list_plugins())
+}
+
+#[tauri::command]
+pub async fn remove_external_plugin(
+    name: String,
+    registry: tauri::State<'_, ExternalPluginRegistry>,
+) -> Result<(), String> {
+    registry
+        .manager
+        .remove_plugin(&name)
+        .map_err(|e| format!("Failed to remove plugin: {}", e))?;
+    registry.plugins.lock().unwrap().retain(|p| p.name() != name);
+    Ok(())
+}
+
+/// Dispatches a JSON-RPC call to a running plugin by name, surfacing a
+/// crashed or misbehaving plugin as a plain error string rather than taking
+/// down the rest of the app. `call()` is blocking I/O, so it runs on a
+/// `spawn_blocking` thread under `EXTERNAL_PLUGIN_CALL_TIMEOUT`: a plugin
+/// that hangs without crashing still frees this command instead of stalling
+/// its Tokio worker (and everyone waiting behind it) indefinitely. Since
+/// that timeout can't cancel the blocking read the thread is stuck in, a
+/// timed-out plugin is killed and respawned (`restart_hung_plugin`) so it
+/// doesn't stay wedged -- and everyone else's calls to it deadlocked --
+/// past this one.
+#[tauri::command]
+pub async fn call_external_plugin(
+    plugin_name: String,
+    method: String,
+    params: Value,
+    registry: tauri::State<'_, ExternalPluginRegistry>,
+) -> Result<Value, String> {
+    let plugin = registry
+        .find(&plugin_name)
+        .ok_or_else(|| format!("No external plugin named '{}' is running", plugin_name))?;
+
+    let method_for_timeout = method.clone();
+    let outcome = tokio::time::timeout(
+        EXTERNAL_PLUGIN_CALL_TIMEOUT,
+        tokio::task::spawn_blocking(move || plugin.call(&method, params)),
+    )
+    .await;
+
+    match outcome {
+        Ok(Ok(result)) => result.map_err(|e| format!("Plugin call failed: {}", e)),
+        Ok(Err(join_err)) => Err(format!("Plugin '{}' call task panicked: {}", plugin_name, join_err)),
+        Err(_) => {
+            restart_hung_plugin(&registry, &plugin_name);
+            Err(format!(
+                "Plugin '{}' did not respond to '{}' within the call timeout",
+                plugin_name, method_for_timeout
+            ))
+        }
+    }
+}
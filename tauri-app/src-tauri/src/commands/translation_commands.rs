@@ -0,0 +1,16 @@
+use crate::error::ToTauriResult;
+use crate::services::TranslationService;
+use std::collections::HashMap;
+
+#[tauri::command]
+pub async fn translate_text(
+    text: String,
+    source_lang: String,
+    target_langs: Vec<String>,
+) -> Result<HashMap<String, String>, String> {
+    let translation_service = TranslationService::new();
+    translation_service
+        .translate_to_many(&text, &source_lang, &target_langs, Some(2000))
+        .await
+        .to_tauri_result()
+}
@@ -37,13 +37,54 @@ pub enum ErrorCode {
     // Batch processing errors
     BatchProcessing,
     BatchCancellation,
-    
+
+    // Media limits errors
+    MediaDimensions,
+    MediaTooLong,
+    TooManyFrames,
+
+    // External tool errors (ffmpeg, tesseract, git, ...)
+    ExternalToolFailed,
+    ExternalToolNotFound,
+
+    // External plugin errors (subprocess OCR/grammar/export providers)
+    PluginError,
+
     // General errors
     InvalidInput,
     ServiceUnavailable,
     InternalError,
 }
 
+/// Broad class of failure, for the frontend to decide how to react — offer a
+/// retry, blame the input, or just show a generic failure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ErrorCategory {
+    ClientError,
+    ServiceUnavailable,
+    Internal,
+}
+
+/// Serializable error payload for Tauri commands that want the frontend to
+/// know more than just a message: whether this is the user's fault, a
+/// dependency worth retrying, or an internal bug.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AppErrorResponse {
+    pub message: String,
+    pub category: ErrorCategory,
+    pub retryable: bool,
+}
+
+impl From<&AppError> for AppErrorResponse {
+    fn from(err: &AppError) -> Self {
+        Self {
+            message: err.to_tauri_error(),
+            category: err.category(),
+            retryable: err.is_retryable(),
+        }
+    }
+}
+
 impl AppError {
     pub fn new(code: ErrorCode, message: impl Into<String>) -> Self {
         Self {
@@ -68,6 +109,89 @@ impl AppError {
             None => self.message.clone(),
         }
     }
+
+    /// Broad failure class derived from `code`, for frontend error styling.
+    pub fn category(&self) -> ErrorCategory {
+        match self.code {
+            ErrorCode::InvalidInput
+            | ErrorCode::InvalidFileFormat
+            | ErrorCode::FileNotFound
+            | ErrorCode::FileAccess
+            | ErrorCode::FileValidation
+            | ErrorCode::DataValidation
+            | ErrorCode::MediaDimensions
+            | ErrorCode::MediaTooLong
+            | ErrorCode::TooManyFrames
+            | ErrorCode::ExternalToolNotFound => ErrorCategory::ClientError,
+
+            ErrorCode::LanguageToolConnection
+            | ErrorCode::ServiceUnavailable
+            | ErrorCode::PluginError => ErrorCategory::ServiceUnavailable,
+
+            _ => ErrorCategory::Internal,
+        }
+    }
+
+    /// Whether retrying the same request unchanged might succeed — true only
+    /// for transient service-availability failures, never for client
+    /// mistakes or internal bugs.
+    pub fn is_retryable(&self) -> bool {
+        matches!(self.category(), ErrorCategory::ServiceUnavailable)
+    }
+
+    /// Builds an `ExternalToolFailed` error from `tool`'s failed output,
+    /// capturing the exit status and a truncated stderr tail so a failed
+    /// `ffmpeg`/`tesseract`/`git` invocation surfaces actionable detail
+    /// instead of collapsing into a generic internal error.
+    pub fn from_process_output(tool: &str, output: &std::process::Output) -> Self {
+        const STDERR_TAIL_LIMIT: usize = 2000;
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let stderr_tail = if stderr.len() > STDERR_TAIL_LIMIT {
+            // `len() - STDERR_TAIL_LIMIT` is a byte offset that can land
+            // mid-character; walk back from the end by char count instead
+            // to find a boundary that's actually safe to slice at.
+            let boundary = stderr
+                .char_indices()
+                .rev()
+                .nth(STDERR_TAIL_LIMIT - 1)
+                .map(|(i, _)| i)
+                .unwrap_or(0);
+            &stderr[boundary..]
+        } else {
+            &stderr
+        };
+
+        let status = output
+            .status
+            .code()
+            .map(|code| code.to_string())
+            .unwrap_or_else(|| "unknown".to_string());
+
+        AppError::with_details(
+            ErrorCode::ExternalToolFailed,
+            format!("{} exited with status {}", tool, status),
+            stderr_tail.trim().to_string(),
+        )
+    }
+
+    /// Maps a spawn failure to `ExternalToolNotFound` when the OS couldn't
+    /// find `tool` on `PATH`, otherwise falls back to a generic internal
+    /// error for other spawn failures (e.g. permission denied).
+    pub fn from_spawn_error(tool: &str, error: &std::io::Error) -> Self {
+        if error.kind() == std::io::ErrorKind::NotFound {
+            AppError::with_details(
+                ErrorCode::ExternalToolNotFound,
+                format!("{} was not found on this system", tool),
+                error.to_string(),
+            )
+        } else {
+            AppError::with_details(
+                ErrorCode::InternalError,
+                format!("Failed to run {}", tool),
+                error.to_string(),
+            )
+        }
+    }
 }
 
 impl std::fmt::Display for AppError {
@@ -110,18 +234,30 @@ pub type AppResult<T> = Result<T, AppError>;
 /// Trait for converting results to Tauri-compatible string errors
 pub trait ToTauriResult<T> {
     fn to_tauri_result(self) -> Result<T, String>;
+
+    /// Like `to_tauri_result`, but keeps the error's category and
+    /// retryability instead of flattening everything to a message string.
+    fn to_tauri_response(self) -> Result<T, AppErrorResponse>;
 }
 
 impl<T> ToTauriResult<T> for AppResult<T> {
     fn to_tauri_result(self) -> Result<T, String> {
         self.map_err(|e| e.to_tauri_error())
     }
+
+    fn to_tauri_response(self) -> Result<T, AppErrorResponse> {
+        self.map_err(|e| AppErrorResponse::from(&e))
+    }
 }
 
 impl<T> ToTauriResult<T> for AnyhowResult<T> {
     fn to_tauri_result(self) -> Result<T, String> {
         self.map_err(|e| e.to_string())
     }
+
+    fn to_tauri_response(self) -> Result<T, AppErrorResponse> {
+        self.map_err(|e| AppErrorResponse::from(&AppError::new(ErrorCode::InternalError, e.to_string())))
+    }
 }
 
 /// Convenience macros for creating errors
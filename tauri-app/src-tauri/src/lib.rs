@@ -3,6 +3,8 @@ mod commands;
 
 use commands::*;
 use services::*;
+use std::sync::Arc;
+use tauri::Manager;
 use tokio::sync::Mutex;
 
 // Initialize services
@@ -44,6 +46,9 @@ pub fn run() {
             extract_video_frames,
             // Grammar commands
             check_grammar,
+            check_grammar_lazy,
+            check_grammar_incremental,
+            resolve_correction,
             smart_grammar_check,
             apply_specific_corrections,
             apply_selective_corrections,
@@ -52,8 +57,15 @@ pub fn run() {
             set_grammar_config,
             get_grammar_providers,
             get_supported_languages,
+            install_rule_pack,
+            list_rule_packs,
+            remove_rule_pack,
+            install_grammar_plugin,
+            list_grammar_plugins,
+            remove_grammar_plugin,
             // File commands
             get_file_info,
+            get_media_metadata,
             validate_file_path,
             is_supported_image,
             is_supported_video,
@@ -64,6 +76,7 @@ pub fn run() {
             extract_text_from_document,
             extract_text_from_pdf,
             extract_frames_from_video,
+            extract_archive_images,
             format_file_size,
             create_backup_path,
             ensure_directory_exists,
@@ -73,6 +86,7 @@ pub fn run() {
             export_multiple_to_csv,
             read_csv_file,
             get_csv_statistics,
+            import_csv_resilient,
             create_csv_backup,
             validate_export_record,
             create_export_record,
@@ -82,12 +96,59 @@ pub fn run() {
             cancel_batch_processing,
             batch_export_results,
             get_batch_statistics,
+            // Translation commands
+            translate_text,
+            // Result cache commands
+            check_result_cache,
+            store_cached_result,
+            // External plugin commands
+            install_external_plugin,
+            list_external_plugins,
+            remove_external_plugin,
+            call_external_plugin,
         ])
-        .setup(|_app| {
+        .setup(|app| {
+            let app_handle = app.handle().clone();
+            let result_cache = app_handle
+                .path()
+                .app_data_dir()
+                .ok()
+                .and_then(|dir| ResultCache::new(&dir).ok());
+            let cache_entries = result_cache
+                .as_ref()
+                .map(ResultCache::load)
+                .unwrap_or_default();
+            app.manage(ResultCacheState(Arc::new(cache_entries)));
+            if let Some(result_cache) = result_cache {
+                app.manage(ResultCacheHandle(result_cache));
+            }
+
+            if let Ok(plugins_dir) = app_handle.path().app_data_dir() {
+                match ExternalPluginManager::new(&plugins_dir) {
+                    Ok(manager) => {
+                        app.manage(ExternalPluginRegistry::new(manager));
+                    }
+                    Err(e) => log::warn!("Failed to initialize external plugin registry: {}", e),
+                }
+            }
+
             // Setup complete
             println!("OCR & Grammar Assistant started successfully!");
             Ok(())
         })
+        .on_window_event(|window, event| {
+            if let tauri::WindowEvent::CloseRequested { .. } = event {
+                let app_handle = window.app_handle();
+                if let (Some(cache_state), Some(cache_handle)) = (
+                    app_handle.try_state::<ResultCacheState>(),
+                    app_handle.try_state::<ResultCacheHandle>(),
+                ) {
+                    if let Err(e) = cache_handle.0.save(&cache_state.0) {
+                        log::warn!("Failed to persist result cache on shutdown: {}", e);
+                    }
+                }
+            }
+        })
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
 }
@@ -1,8 +1,12 @@
 use anyhow::{Result, anyhow};
 use csv::Writer;
 use serde::{Deserialize, Serialize};
+use std::cell::Cell;
+use std::collections::BTreeMap;
 use std::fs::OpenOptions;
+use std::io::Read;
 use std::path::Path;
+use std::rc::Rc;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ExportRecord {
@@ -15,6 +19,11 @@ pub struct ExportRecord {
     pub processing_time: f64,
     pub source_type: String,
     pub error_summary: String,
+    /// `translation_<lang>` -> translated text (see `translation_column`).
+    /// Flattened so each target language becomes its own CSV column instead
+    /// of one JSON blob column.
+    #[serde(flatten, default)]
+    pub translations: BTreeMap<String, String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -34,6 +43,16 @@ impl Default for ExportOptions {
     }
 }
 
+/// Outcome of `CSVExporterService::read_csv_file_streaming`: how much of a
+/// recovered file actually made it through, so the UI can warn the user
+/// when rows were dropped or characters were substituted.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CsvImportSummary {
+    pub records_read: usize,
+    pub rows_skipped: usize,
+    pub substituted_bytes: usize,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ExportStatistics {
     pub total_records: usize,
@@ -42,6 +61,9 @@ pub struct ExportStatistics {
     pub last_export: String,
     pub ocr_engines_used: std::collections::HashMap<String, usize>,
     pub file_size_mb: f64,
+    pub average_ocr_confidence: f64,
+    pub average_processing_time: f64,
+    pub total_characters_corrected: usize,
 }
 
 pub struct CSVExporterService;
@@ -51,6 +73,23 @@ impl CSVExporterService {
         Self
     }
 
+    /// Column name for a translation into `lang` (e.g. `"en"` -> `"translation_en"`).
+    pub fn translation_column(lang: &str) -> String {
+        format!("translation_{}", lang)
+    }
+
+    /// Union of translation columns across `records`, sorted for a stable
+    /// header order regardless of which record introduced each language.
+    pub(crate) fn translation_columns(records: &[&ExportRecord]) -> Vec<String> {
+        let mut columns: Vec<String> = records
+            .iter()
+            .flat_map(|r| r.translations.keys().cloned())
+            .collect();
+        columns.sort();
+        columns.dedup();
+        columns
+    }
+
     pub fn export_record(
         file_path: &str,
         record: &ExportRecord,
@@ -79,20 +118,23 @@ impl CSVExporterService {
         };
 
         let mut writer = Writer::from_writer(file);
+        let translation_columns = Self::translation_columns(&[record]);
 
         // Write headers if needed
         if write_headers {
-            writer.write_record(&[
-                "Timestamp",
-                "OriginalText",
-                "CorrectedText",
-                "GrammarErrorCount",
-                "OCREngine",
-                "OCRConfidence",
-                "ProcessingTime",
-                "SourceType",
-                "ErrorSummary",
-            ])?;
+            let mut headers = vec![
+                "Timestamp".to_string(),
+                "OriginalText".to_string(),
+                "CorrectedText".to_string(),
+                "GrammarErrorCount".to_string(),
+                "OCREngine".to_string(),
+                "OCRConfidence".to_string(),
+                "ProcessingTime".to_string(),
+                "SourceType".to_string(),
+                "ErrorSummary".to_string(),
+            ];
+            headers.extend(translation_columns.iter().cloned());
+            writer.write_record(&headers)?;
         }
 
         // Clean and prepare record data
@@ -101,20 +143,24 @@ impl CSVExporterService {
         let clean_error_summary = Self::clean_text_for_csv(&record.error_summary, opts.max_text_length);
 
         // Write data record
-        writer.write_record(&[
-            &record.timestamp,
-            &clean_original,
-            &clean_corrected,
-            &record.grammar_error_count.to_string(),
-            &record.ocr_engine,
-            &format!("{:.3}", record.ocr_confidence),
-            &format!("{:.2}", record.processing_time),
-            &record.source_type,
-            &clean_error_summary,
-        ])?;
+        let mut row = vec![
+            record.timestamp.clone(),
+            clean_original,
+            clean_corrected,
+            record.grammar_error_count.to_string(),
+            record.ocr_engine.clone(),
+            format!("{:.3}", record.ocr_confidence),
+            format!("{:.2}", record.processing_time),
+            record.source_type.clone(),
+            clean_error_summary,
+        ];
+        for column in &translation_columns {
+            row.push(record.translations.get(column).cloned().unwrap_or_default());
+        }
+        writer.write_record(&row)?;
 
         writer.flush()?;
-        
+
         log::info!("Successfully exported record to {}", file_path);
         Ok(())
     }
@@ -151,20 +197,23 @@ impl CSVExporterService {
         };
 
         let mut writer = Writer::from_writer(file);
+        let translation_columns = Self::translation_columns(&records.iter().collect::<Vec<_>>());
 
         // Write headers if needed
         if write_headers {
-            writer.write_record(&[
-                "Timestamp",
-                "OriginalText",
-                "CorrectedText",
-                "GrammarErrorCount",
-                "OCREngine",
-                "OCRConfidence",
-                "ProcessingTime",
-                "SourceType",
-                "ErrorSummary",
-            ])?;
+            let mut headers = vec![
+                "Timestamp".to_string(),
+                "OriginalText".to_string(),
+                "CorrectedText".to_string(),
+                "GrammarErrorCount".to_string(),
+                "OCREngine".to_string(),
+                "OCRConfidence".to_string(),
+                "ProcessingTime".to_string(),
+                "SourceType".to_string(),
+                "ErrorSummary".to_string(),
+            ];
+            headers.extend(translation_columns.iter().cloned());
+            writer.write_record(&headers)?;
         }
 
         // Write all records
@@ -173,21 +222,25 @@ impl CSVExporterService {
             let clean_corrected = Self::clean_text_for_csv(&record.corrected_text, opts.max_text_length);
             let clean_error_summary = Self::clean_text_for_csv(&record.error_summary, opts.max_text_length);
 
-            writer.write_record(&[
-                &record.timestamp,
-                &clean_original,
-                &clean_corrected,
-                &record.grammar_error_count.to_string(),
-                &record.ocr_engine,
-                &format!("{:.3}", record.ocr_confidence),
-                &format!("{:.2}", record.processing_time),
-                &record.source_type,
-                &clean_error_summary,
-            ])?;
+            let mut row = vec![
+                record.timestamp.clone(),
+                clean_original,
+                clean_corrected,
+                record.grammar_error_count.to_string(),
+                record.ocr_engine.clone(),
+                format!("{:.3}", record.ocr_confidence),
+                format!("{:.2}", record.processing_time),
+                record.source_type.clone(),
+                clean_error_summary,
+            ];
+            for column in &translation_columns {
+                row.push(record.translations.get(column).cloned().unwrap_or_default());
+            }
+            writer.write_record(&row)?;
         }
 
         writer.flush()?;
-        
+
         log::info!("Successfully exported {} records to {}", records.len(), file_path);
         Ok(())
     }
@@ -219,48 +272,66 @@ impl CSVExporterService {
         Ok(records)
     }
 
-    pub fn get_export_statistics(file_path: &str) -> Result<ExportStatistics> {
+    /// Like `read_csv_file`, but for export logs too large or too corrupted
+    /// to materialize as one `Vec<ExportRecord>`: invalid UTF-8 bytes are
+    /// replaced rather than erroring, and malformed rows are skipped and
+    /// counted instead of aborting the whole import. Records are handed to
+    /// `on_record` one at a time as they're parsed, so the caller never
+    /// needs to hold the whole file's records in memory at once.
+    pub fn read_csv_file_streaming(
+        file_path: &str,
+        mut on_record: impl FnMut(ExportRecord),
+    ) -> Result<CsvImportSummary> {
         if !Path::new(file_path).exists() {
             return Err(anyhow!("CSV file does not exist: {}", file_path));
         }
 
-        let records = Self::read_csv_file(file_path)?;
-        
-        if records.is_empty() {
-            return Ok(ExportStatistics {
-                total_records: 0,
-                total_grammar_errors: 0,
-                first_export: "N/A".to_string(),
-                last_export: "N/A".to_string(),
-                ocr_engines_used: std::collections::HashMap::new(),
-                file_size_mb: 0.0,
-            });
+        let file = std::fs::File::open(file_path)
+            .map_err(|e| anyhow!("Failed to open CSV file: {}", e))?;
+        let buffered = std::io::BufReader::new(file);
+
+        let substitutions = Rc::new(Cell::new(0usize));
+        let lossy_reader = LossyUtf8Reader::new(buffered, substitutions.clone());
+        let mut csv_reader = csv::ReaderBuilder::new().from_reader(lossy_reader);
+
+        let mut summary = CsvImportSummary::default();
+        for result in csv_reader.deserialize::<ExportRecord>() {
+            match result {
+                Ok(record) => {
+                    summary.records_read += 1;
+                    on_record(record);
+                }
+                Err(e) => {
+                    summary.rows_skipped += 1;
+                    log::warn!("Skipping malformed CSV row in {}: {}", file_path, e);
+                }
+            }
         }
+        summary.substituted_bytes = substitutions.get();
 
-        let total_records = records.len();
-        let total_grammar_errors = records.iter().map(|r| r.grammar_error_count).sum();
-        
-        let timestamps: Vec<&String> = records.iter().map(|r| &r.timestamp).collect();
-        let first_export = timestamps.iter().min().unwrap_or(&&"N/A".to_string()).to_string();
-        let last_export = timestamps.iter().max().unwrap_or(&&"N/A".to_string()).to_string();
+        log::info!(
+            "Streaming import of {} complete: {} records read, {} rows skipped, {} byte(s) substituted",
+            file_path, summary.records_read, summary.rows_skipped, summary.substituted_bytes
+        );
 
-        let mut ocr_engines_used = std::collections::HashMap::new();
-        for record in &records {
-            *ocr_engines_used.entry(record.ocr_engine.clone()).or_insert(0) += 1;
+        Ok(summary)
+    }
+
+    /// Reads `file_path` back in and aggregates it into `ExportStatistics`,
+    /// reporting progress via `on_progress(records_scanned, total_records)`
+    /// as it goes. The actual aggregation -- parallelized with rayon above
+    /// a size threshold -- lives in `exporters::compute_statistics`, shared
+    /// with the JSONL/XLSX exporters so all three formats tally the same way.
+    pub fn get_export_statistics(
+        file_path: &str,
+        on_progress: Option<&(dyn Fn(usize, usize) + Sync)>,
+    ) -> Result<ExportStatistics> {
+        if !Path::new(file_path).exists() {
+            return Err(anyhow!("CSV file does not exist: {}", file_path));
         }
 
-        let file_size_mb = std::fs::metadata(file_path)
-            .map(|m| m.len() as f64 / (1024.0 * 1024.0))
-            .unwrap_or(0.0);
-
-        Ok(ExportStatistics {
-            total_records,
-            total_grammar_errors,
-            first_export,
-            last_export,
-            ocr_engines_used,
-            file_size_mb,
-        })
+        let records = Self::read_csv_file(file_path)?;
+        Ok(crate::services::exporters::compute_statistics(&records, file_path, on_progress))
     }
 
     pub fn create_backup(file_path: &str) -> Result<String> {
@@ -323,3 +394,109 @@ impl CSVExporterService {
         Ok(())
     }
 }
+
+/// Wraps a byte reader and substitutes invalid UTF-8 sequences with the
+/// Unicode replacement character (U+FFFD) as bytes flow through, rather than
+/// erroring on the first bad byte the way reading straight into a `String`
+/// would. `substitutions` is shared with the caller so it can report how
+/// many bytes were substituted after the reader's been consumed downstream.
+struct LossyUtf8Reader<R> {
+    inner: R,
+    raw_buf: Vec<u8>,
+    raw_len: usize,
+    raw_pos: usize,
+    substitutions: Rc<Cell<usize>>,
+}
+
+impl<R: Read> LossyUtf8Reader<R> {
+    fn new(inner: R, substitutions: Rc<Cell<usize>>) -> Self {
+        Self {
+            inner,
+            raw_buf: vec![0u8; 8192],
+            raw_len: 0,
+            raw_pos: 0,
+            substitutions,
+        }
+    }
+
+    fn fill(&mut self) -> std::io::Result<()> {
+        if self.raw_pos > 0 {
+            self.raw_buf.copy_within(self.raw_pos..self.raw_len, 0);
+            self.raw_len -= self.raw_pos;
+            self.raw_pos = 0;
+        }
+        if self.raw_len == self.raw_buf.len() {
+            self.raw_buf.resize(self.raw_buf.len() * 2, 0);
+        }
+        let n = self.inner.read(&mut self.raw_buf[self.raw_len..])?;
+        self.raw_len += n;
+        Ok(())
+    }
+
+    fn note_substitution(&self, bytes: usize) {
+        self.substitutions.set(self.substitutions.get() + bytes);
+    }
+}
+
+impl<R: Read> Read for LossyUtf8Reader<R> {
+    fn read(&mut self, out: &mut [u8]) -> std::io::Result<usize> {
+        loop {
+            if self.raw_pos >= self.raw_len {
+                self.fill()?;
+                if self.raw_len == 0 {
+                    return Ok(0);
+                }
+            }
+
+            let slice = &self.raw_buf[self.raw_pos..self.raw_len];
+            match std::str::from_utf8(slice) {
+                Ok(_) => {
+                    let n = out.len().min(slice.len());
+                    out[..n].copy_from_slice(&slice[..n]);
+                    self.raw_pos += n;
+                    return Ok(n);
+                }
+                Err(e) => {
+                    let valid_up_to = e.valid_up_to();
+                    if valid_up_to > 0 {
+                        let n = out.len().min(valid_up_to);
+                        out[..n].copy_from_slice(&slice[..n]);
+                        self.raw_pos += n;
+                        return Ok(n);
+                    }
+
+                    match e.error_len() {
+                        Some(bad_len) => {
+                            self.note_substitution(bad_len);
+                            self.raw_pos += bad_len;
+                            let replacement = "\u{FFFD}".as_bytes();
+                            let n = out.len().min(replacement.len());
+                            out[..n].copy_from_slice(&replacement[..n]);
+                            return Ok(n);
+                        }
+                        None if self.raw_len - self.raw_pos >= 4 => {
+                            // 4 bytes is the longest possible UTF-8 sequence,
+                            // so an incomplete sequence this long can't be
+                            // waiting on more data — it's simply invalid.
+                            self.note_substitution(1);
+                            self.raw_pos += 1;
+                        }
+                        None => {
+                            let before = self.raw_len;
+                            self.fill()?;
+                            if self.raw_len == before {
+                                // EOF with a truncated trailing sequence.
+                                self.note_substitution(self.raw_len - self.raw_pos);
+                                self.raw_pos = self.raw_len;
+                                let replacement = "\u{FFFD}".as_bytes();
+                                let n = out.len().min(replacement.len());
+                                out[..n].copy_from_slice(&replacement[..n]);
+                                return Ok(n);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,378 @@
+use crate::services::csv_exporter::{CSVExporterService, ExportOptions, ExportRecord, ExportStatistics};
+use anyhow::{anyhow, Result};
+use rayon::prelude::*;
+use std::collections::BTreeMap;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Backend-agnostic export operations, so the command layer can pick an
+/// implementation by format/extension instead of hardcoding CSV everywhere.
+/// Each implementation owns its own append semantics: CSV appends rows,
+/// JSONL appends lines, XLSX has to rewrite the whole workbook.
+pub trait Exporter {
+    fn write_record(&self, file_path: &str, record: &ExportRecord, options: Option<ExportOptions>) -> Result<()>;
+    fn write_batch(&self, file_path: &str, records: &[ExportRecord], options: Option<ExportOptions>) -> Result<()>;
+    fn read_records(&self, file_path: &str) -> Result<Vec<ExportRecord>>;
+
+    /// Aggregates the file's records into `ExportStatistics`, reporting
+    /// `on_progress(records_scanned, total_records)` as it goes so a large
+    /// log can show a progress bar instead of hanging silently.
+    fn statistics(&self, file_path: &str, on_progress: Option<&(dyn Fn(usize, usize) + Sync)>) -> Result<ExportStatistics>;
+}
+
+/// Delegates to the original `CSVExporterService`, so existing CSV export
+/// behavior (append semantics, translation columns, text cleaning) is
+/// unchanged by this refactor.
+pub struct CsvExporter;
+
+impl Exporter for CsvExporter {
+    fn write_record(&self, file_path: &str, record: &ExportRecord, options: Option<ExportOptions>) -> Result<()> {
+        CSVExporterService::export_record(file_path, record, options)
+    }
+
+    fn write_batch(&self, file_path: &str, records: &[ExportRecord], options: Option<ExportOptions>) -> Result<()> {
+        CSVExporterService::export_multiple_records(file_path, records, options)
+    }
+
+    fn read_records(&self, file_path: &str) -> Result<Vec<ExportRecord>> {
+        CSVExporterService::read_csv_file(file_path)
+    }
+
+    fn statistics(&self, file_path: &str, on_progress: Option<&(dyn Fn(usize, usize) + Sync)>) -> Result<ExportStatistics> {
+        CSVExporterService::get_export_statistics(file_path, on_progress)
+    }
+}
+
+/// One JSON-encoded `ExportRecord` per line — cheap to stream and append to
+/// without rewriting the whole file, which is why logs that outlive a
+/// single session (continuous batch runs) are a better fit for this than CSV.
+pub struct JsonLinesExporter;
+
+impl Exporter for JsonLinesExporter {
+    fn write_record(&self, file_path: &str, record: &ExportRecord, options: Option<ExportOptions>) -> Result<()> {
+        self.write_batch(file_path, std::slice::from_ref(record), options)
+    }
+
+    fn write_batch(&self, file_path: &str, records: &[ExportRecord], options: Option<ExportOptions>) -> Result<()> {
+        let opts = options.unwrap_or_default();
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(opts.append_mode)
+            .write(true)
+            .truncate(!opts.append_mode)
+            .open(file_path)
+            .map_err(|e| anyhow!("Failed to open file for JSONL export: {}", e))?;
+
+        for record in records {
+            writeln!(file, "{}", serde_json::to_string(record)?)?;
+        }
+
+        Ok(())
+    }
+
+    fn read_records(&self, file_path: &str) -> Result<Vec<ExportRecord>> {
+        if !Path::new(file_path).exists() {
+            return Err(anyhow!("JSONL file does not exist: {}", file_path));
+        }
+
+        let content = std::fs::read_to_string(file_path)
+            .map_err(|e| anyhow!("Failed to read JSONL file: {}", e))?;
+
+        let mut records = Vec::new();
+        for line in content.lines() {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            match serde_json::from_str::<ExportRecord>(line) {
+                Ok(record) => records.push(record),
+                Err(e) => log::warn!("Failed to parse JSONL record: {}", e),
+            }
+        }
+
+        Ok(records)
+    }
+
+    fn statistics(&self, file_path: &str, on_progress: Option<&(dyn Fn(usize, usize) + Sync)>) -> Result<ExportStatistics> {
+        let records = self.read_records(file_path)?;
+        Ok(compute_statistics(&records, file_path, on_progress))
+    }
+}
+
+/// Writes/reads an Excel workbook. XLSX has no cheap append-in-place story
+/// the way CSV/JSONL do, so `append_mode` here means "read the existing
+/// sheet back in, then rewrite it with the new records appended".
+pub struct XlsxExporter;
+
+impl Exporter for XlsxExporter {
+    fn write_record(&self, file_path: &str, record: &ExportRecord, options: Option<ExportOptions>) -> Result<()> {
+        self.write_batch(file_path, std::slice::from_ref(record), options)
+    }
+
+    fn write_batch(&self, file_path: &str, records: &[ExportRecord], options: Option<ExportOptions>) -> Result<()> {
+        let opts = options.unwrap_or_default();
+
+        let mut all_records = if opts.append_mode && Path::new(file_path).exists() {
+            self.read_records(file_path)?
+        } else {
+            Vec::new()
+        };
+        all_records.extend_from_slice(records);
+
+        let translation_columns =
+            CSVExporterService::translation_columns(&all_records.iter().collect::<Vec<_>>());
+
+        let mut headers: Vec<String> = [
+            "Timestamp",
+            "OriginalText",
+            "CorrectedText",
+            "GrammarErrorCount",
+            "OCREngine",
+            "OCRConfidence",
+            "ProcessingTime",
+            "SourceType",
+            "ErrorSummary",
+        ]
+        .into_iter()
+        .map(String::from)
+        .collect();
+        headers.extend(translation_columns.iter().cloned());
+
+        let mut workbook = rust_xlsxwriter::Workbook::new();
+        let worksheet = workbook.add_worksheet();
+
+        if opts.include_headers {
+            for (col, header) in headers.iter().enumerate() {
+                worksheet.write_string(0, col as u16, header)?;
+            }
+        }
+
+        let row_offset: u32 = if opts.include_headers { 1 } else { 0 };
+        for (i, record) in all_records.iter().enumerate() {
+            let row = row_offset + i as u32;
+            worksheet.write_string(row, 0, &record.timestamp)?;
+            worksheet.write_string(row, 1, Self::truncate(&record.original_text, opts.max_text_length))?;
+            worksheet.write_string(row, 2, Self::truncate(&record.corrected_text, opts.max_text_length))?;
+            worksheet.write_number(row, 3, record.grammar_error_count as f64)?;
+            worksheet.write_string(row, 4, &record.ocr_engine)?;
+            worksheet.write_number(row, 5, record.ocr_confidence as f64)?;
+            worksheet.write_number(row, 6, record.processing_time)?;
+            worksheet.write_string(row, 7, &record.source_type)?;
+            worksheet.write_string(row, 8, Self::truncate(&record.error_summary, opts.max_text_length))?;
+
+            for (j, column) in translation_columns.iter().enumerate() {
+                let value = record.translations.get(column).cloned().unwrap_or_default();
+                worksheet.write_string(row, 9 + j as u16, &value)?;
+            }
+        }
+
+        workbook.save(file_path)?;
+        Ok(())
+    }
+
+    fn read_records(&self, file_path: &str) -> Result<Vec<ExportRecord>> {
+        if !Path::new(file_path).exists() {
+            return Err(anyhow!("XLSX file does not exist: {}", file_path));
+        }
+
+        let mut workbook: calamine::Xlsx<_> = calamine::open_workbook(file_path)
+            .map_err(|e| anyhow!("Failed to open XLSX file: {}", e))?;
+        let range = workbook
+            .worksheet_range("Sheet1")
+            .map_err(|e| anyhow!("Failed to read XLSX worksheet: {}", e))?;
+
+        let mut rows = range.rows();
+        let headers: Vec<String> = rows
+            .next()
+            .map(|row| row.iter().map(|cell| cell.to_string()).collect())
+            .unwrap_or_default();
+
+        let translation_indices: Vec<(usize, String)> = headers
+            .iter()
+            .enumerate()
+            .filter(|(_, h)| h.starts_with("translation_"))
+            .map(|(i, h)| (i, h.clone()))
+            .collect();
+
+        let mut records = Vec::new();
+        for row in rows {
+            let get = |i: usize| row.get(i).map(|c| c.to_string()).unwrap_or_default();
+
+            let mut translations = BTreeMap::new();
+            for (index, lang) in &translation_indices {
+                let value = get(*index);
+                if !value.is_empty() {
+                    translations.insert(lang.clone(), value);
+                }
+            }
+
+            records.push(ExportRecord {
+                timestamp: get(0),
+                original_text: get(1),
+                corrected_text: get(2),
+                grammar_error_count: get(3).parse().unwrap_or(0),
+                ocr_engine: get(4),
+                ocr_confidence: get(5).parse().unwrap_or(0.0),
+                processing_time: get(6).parse().unwrap_or(0.0),
+                source_type: get(7),
+                error_summary: get(8),
+                translations,
+            });
+        }
+
+        Ok(records)
+    }
+
+    fn statistics(&self, file_path: &str, on_progress: Option<&(dyn Fn(usize, usize) + Sync)>) -> Result<ExportStatistics> {
+        let records = self.read_records(file_path)?;
+        Ok(compute_statistics(&records, file_path, on_progress))
+    }
+}
+
+impl XlsxExporter {
+    fn truncate(text: &str, max_length: usize) -> &str {
+        if text.len() > max_length {
+            &text[..max_length]
+        } else {
+            text
+        }
+    }
+}
+
+/// Below this many records, chunking the work for rayon costs more than it
+/// saves -- just fold over the slice on the calling thread.
+const PARALLEL_STATS_THRESHOLD: usize = 5_000;
+
+/// One worker's tally over a chunk of records, combined with every other
+/// chunk's tally via `merge` -- an associative reduction, so chunk order
+/// (and therefore thread scheduling) can't affect the final result.
+#[derive(Default)]
+struct StatsPartial {
+    count: usize,
+    total_grammar_errors: usize,
+    total_characters_corrected: usize,
+    confidence_sum: f64,
+    processing_time_sum: f64,
+    ocr_engines_used: std::collections::HashMap<String, usize>,
+    first_timestamp: Option<String>,
+    last_timestamp: Option<String>,
+}
+
+impl StatsPartial {
+    fn push(mut self, record: &ExportRecord) -> Self {
+        self.count += 1;
+        self.total_grammar_errors += record.grammar_error_count;
+        self.total_characters_corrected += record.corrected_text.chars().count();
+        self.confidence_sum += record.ocr_confidence as f64;
+        self.processing_time_sum += record.processing_time;
+        *self.ocr_engines_used.entry(record.ocr_engine.clone()).or_insert(0) += 1;
+
+        if self.first_timestamp.as_deref().map_or(true, |t| record.timestamp.as_str() < t) {
+            self.first_timestamp = Some(record.timestamp.clone());
+        }
+        if self.last_timestamp.as_deref().map_or(true, |t| record.timestamp.as_str() > t) {
+            self.last_timestamp = Some(record.timestamp.clone());
+        }
+
+        self
+    }
+
+    fn merge(mut self, other: Self) -> Self {
+        self.count += other.count;
+        self.total_grammar_errors += other.total_grammar_errors;
+        self.total_characters_corrected += other.total_characters_corrected;
+        self.confidence_sum += other.confidence_sum;
+        self.processing_time_sum += other.processing_time_sum;
+
+        for (engine, count) in other.ocr_engines_used {
+            *self.ocr_engines_used.entry(engine).or_insert(0) += count;
+        }
+
+        self.first_timestamp = match (self.first_timestamp, other.first_timestamp) {
+            (Some(a), Some(b)) => Some(if a <= b { a } else { b }),
+            (a, b) => a.or(b),
+        };
+        self.last_timestamp = match (self.last_timestamp, other.last_timestamp) {
+            (Some(a), Some(b)) => Some(if a >= b { a } else { b }),
+            (a, b) => a.or(b),
+        };
+
+        self
+    }
+}
+
+/// Shared statistics aggregation for all three exporters. Record chunks are
+/// folded in parallel with rayon above `PARALLEL_STATS_THRESHOLD`, each
+/// worker producing a `StatsPartial` that's merged via an associative
+/// reduce; smaller files fall back to a single-threaded fold, since
+/// chunking overhead would dominate the actual work.
+pub(crate) fn compute_statistics(
+    records: &[ExportRecord],
+    file_path: &str,
+    on_progress: Option<&(dyn Fn(usize, usize) + Sync)>,
+) -> ExportStatistics {
+    let total_records = records.len();
+
+    if total_records == 0 {
+        return ExportStatistics {
+            total_records: 0,
+            total_grammar_errors: 0,
+            first_export: "N/A".to_string(),
+            last_export: "N/A".to_string(),
+            ocr_engines_used: std::collections::HashMap::new(),
+            file_size_mb: 0.0,
+            average_ocr_confidence: 0.0,
+            average_processing_time: 0.0,
+            total_characters_corrected: 0,
+        };
+    }
+
+    let partial = if total_records >= PARALLEL_STATS_THRESHOLD {
+        let scanned = AtomicUsize::new(0);
+        let chunk_size = (total_records / rayon::current_num_threads().max(1)).max(1);
+
+        records
+            .par_chunks(chunk_size)
+            .map(|chunk| {
+                let partial = chunk.iter().fold(StatsPartial::default(), StatsPartial::push);
+                let scanned_so_far = scanned.fetch_add(chunk.len(), Ordering::Relaxed) + chunk.len();
+                if let Some(on_progress) = on_progress {
+                    on_progress(scanned_so_far, total_records);
+                }
+                partial
+            })
+            .reduce(StatsPartial::default, StatsPartial::merge)
+    } else {
+        const PROGRESS_STEP: usize = 100;
+        let mut partial = StatsPartial::default();
+        for (index, record) in records.iter().enumerate() {
+            partial = partial.push(record);
+            let scanned = index + 1;
+            if let Some(on_progress) = on_progress {
+                if scanned % PROGRESS_STEP == 0 || scanned == total_records {
+                    on_progress(scanned, total_records);
+                }
+            }
+        }
+        partial
+    };
+
+    let file_size_mb = std::fs::metadata(file_path)
+        .map(|m| m.len() as f64 / (1024.0 * 1024.0))
+        .unwrap_or(0.0);
+
+    ExportStatistics {
+        total_records: partial.count,
+        total_grammar_errors: partial.total_grammar_errors,
+        first_export: partial.first_timestamp.unwrap_or_else(|| "N/A".to_string()),
+        last_export: partial.last_timestamp.unwrap_or_else(|| "N/A".to_string()),
+        ocr_engines_used: partial.ocr_engines_used,
+        file_size_mb,
+        average_ocr_confidence: partial.confidence_sum / partial.count as f64,
+        average_processing_time: partial.processing_time_sum / partial.count as f64,
+        total_characters_corrected: partial.total_characters_corrected,
+    }
+}
@@ -0,0 +1,334 @@
+use crate::error::{AppError, AppResult, ErrorCode};
+use crate::utils::path_utils;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fs;
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Child, ChildStdin, ChildStdout, Command, Stdio};
+use std::sync::Mutex;
+
+/// What a plugin provides, so discovered commands can be offered alongside
+/// the matching built-in subsystem instead of needing their own UI surface.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PluginKind {
+    Ocr,
+    Grammar,
+    Export,
+}
+
+/// Declared by the plugin during the handshake: what kind of provider it is
+/// and which JSON-RPC methods it implements.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PluginSignature {
+    pub kind: PluginKind,
+    pub commands: Vec<String>,
+}
+
+/// A configured plugin binary, persisted in the registry manifest.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExternalPluginInfo {
+    pub name: String,
+    pub binary_path: String,
+    pub signature: PluginSignature,
+}
+
+#[derive(Serialize)]
+struct PluginRequest<'a> {
+    jsonrpc: &'a str,
+    id: u64,
+    method: &'a str,
+    params: Value,
+}
+
+#[derive(Deserialize)]
+struct PluginResponse {
+    #[serde(default)]
+    result: Option<Value>,
+    #[serde(default)]
+    error: Option<String>,
+}
+
+/// One spawned plugin subprocess, talked to over piped stdin/stdout: each
+/// call writes a single JSON-RPC request line and reads a single framed JSON
+/// response line back. A plugin that exits or writes garbage surfaces as a
+/// `PluginError` rather than taking the app down with it.
+pub struct ExternalPlugin {
+    name: String,
+    signature: PluginSignature,
+    child: Mutex<Child>,
+    stdin: Mutex<ChildStdin>,
+    stdout: Mutex<BufReader<ChildStdout>>,
+    next_id: Mutex<u64>,
+}
+
+impl ExternalPlugin {
+    /// Spawns `binary_path` with piped stdin/stdout/stderr and performs the
+    /// handshake: sends a `handshake` request with no params and expects a
+    /// `PluginSignature`-shaped result back.
+    pub fn spawn(name: &str, binary_path: &Path) -> AppResult<Self> {
+        let mut child = Command::new(binary_path)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| AppError::from_spawn_error(name, &e))?;
+
+        let stdin = child
+            .stdin
+            .take()
+            .ok_or_else(|| AppError::new(ErrorCode::PluginError, "Plugin stdin is not piped"))?;
+        let stdout = child
+            .stdout
+            .take()
+            .ok_or_else(|| AppError::new(ErrorCode::PluginError, "Plugin stdout is not piped"))?;
+
+        let mut stdin = Mutex::new(stdin);
+        let mut stdout = Mutex::new(BufReader::new(stdout));
+
+        let signature = Self::handshake(name, &mut stdin, &mut stdout)?;
+
+        Ok(Self {
+            name: name.to_string(),
+            signature,
+            child: Mutex::new(child),
+            stdin,
+            stdout,
+            next_id: Mutex::new(1),
+        })
+    }
+
+    fn handshake(
+        name: &str,
+        stdin: &mut Mutex<ChildStdin>,
+        stdout: &mut Mutex<BufReader<ChildStdout>>,
+    ) -> AppResult<PluginSignature> {
+        let request = PluginRequest {
+            jsonrpc: "2.0",
+            id: 0,
+            method: "handshake",
+            params: Value::Null,
+        };
+        Self::write_request(stdin.get_mut().unwrap(), &request)?;
+        let response = Self::read_response(stdout.get_mut().unwrap(), name)?;
+
+        let result = response.result.ok_or_else(|| {
+            AppError::with_details(
+                ErrorCode::PluginError,
+                format!("Plugin '{}' did not declare a signature during handshake", name),
+                response.error.unwrap_or_default(),
+            )
+        })?;
+
+        serde_json::from_value(result).map_err(|e| {
+            AppError::with_details(
+                ErrorCode::PluginError,
+                format!("Plugin '{}' returned an invalid handshake signature", name),
+                e.to_string(),
+            )
+        })
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn signature(&self) -> &PluginSignature {
+        &self.signature
+    }
+
+    /// Forcibly terminates the plugin's child process. The caller of
+    /// `call()` can bound how long it *waits* for a response (see
+    /// `commands/plugin_commands.rs`'s `EXTERNAL_PLUGIN_CALL_TIMEOUT`), but
+    /// can't cancel the blocking `read_line` a hung call is stuck in --
+    /// killing the child is what actually unblocks it (as an EOF on
+    /// `stdout`), freeing the `stdin`/`stdout` locks that call is still
+    /// holding so later calls to this plugin don't deadlock on them.
+    pub fn kill(&self) {
+        if let Ok(mut child) = self.child.lock() {
+            let _ = child.kill();
+        }
+    }
+
+    /// Dispatches `method`/`params` to the plugin and waits for its framed
+    /// JSON response on stdout. Requests are serialized through a lock since
+    /// a plugin subprocess processes one request at a time.
+    pub fn call(&self, method: &str, params: Value) -> AppResult<Value> {
+        let id = {
+            let mut next_id = self.next_id.lock().unwrap();
+            let id = *next_id;
+            *next_id += 1;
+            id
+        };
+
+        let request = PluginRequest {
+            jsonrpc: "2.0",
+            id,
+            method,
+            params,
+        };
+
+        let mut stdin = self.stdin.lock().unwrap();
+        Self::write_request(&mut stdin, &request)?;
+
+        let mut stdout = self.stdout.lock().unwrap();
+        let response = Self::read_response(&mut stdout, &self.name)?;
+
+        response.result.ok_or_else(|| {
+            AppError::with_details(
+                ErrorCode::PluginError,
+                format!("Plugin '{}' returned an error for method '{}'", self.name, method),
+                response.error.unwrap_or_default(),
+            )
+        })
+    }
+
+    fn write_request(stdin: &mut ChildStdin, request: &PluginRequest) -> AppResult<()> {
+        let mut line = serde_json::to_string(request).map_err(|e| {
+            AppError::with_details(ErrorCode::PluginError, "Failed to encode plugin request", e.to_string())
+        })?;
+        line.push('\n');
+
+        stdin
+            .write_all(line.as_bytes())
+            .and_then(|_| stdin.flush())
+            .map_err(|e| {
+                AppError::with_details(ErrorCode::PluginError, "Failed to write request to plugin", e.to_string())
+            })?;
+        Ok(())
+    }
+
+    fn read_response(stdout: &mut BufReader<ChildStdout>, name: &str) -> AppResult<PluginResponse> {
+        let mut line = String::new();
+        let bytes_read = stdout.read_line(&mut line).map_err(|e| {
+            AppError::with_details(
+                ErrorCode::PluginError,
+                format!("Failed to read response from plugin '{}'", name),
+                e.to_string(),
+            )
+        })?;
+
+        if bytes_read == 0 {
+            return Err(AppError::new(
+                ErrorCode::PluginError,
+                format!("Plugin '{}' closed its output stream", name),
+            ));
+        }
+
+        serde_json::from_str(line.trim()).map_err(|e| {
+            AppError::with_details(
+                ErrorCode::PluginError,
+                format!("Plugin '{}' sent a malformed response", name),
+                e.to_string(),
+            )
+        })
+    }
+}
+
+impl Drop for ExternalPlugin {
+    fn drop(&mut self) {
+        if let Ok(mut child) = self.child.lock() {
+            let _ = child.kill();
+        }
+    }
+}
+
+/// Installs, lists and removes external plugin binaries under a runtime
+/// directory, mirroring `GrammarPluginManager`'s manifest-based bookkeeping
+/// -- the difference being these plugins are standalone executables talked
+/// to over stdin/stdout rather than `.wasm` modules run in-process.
+pub struct ExternalPluginManager {
+    plugins_dir: PathBuf,
+}
+
+impl ExternalPluginManager {
+    pub fn new(app_data_dir: &Path) -> AppResult<Self> {
+        let plugins_dir = app_data_dir.join("external_plugins");
+        path_utils::ensure_directory_exists(&plugins_dir.to_string_lossy())?;
+        Ok(Self { plugins_dir })
+    }
+
+    fn manifest_path(&self) -> PathBuf {
+        self.plugins_dir.join("manifest.json")
+    }
+
+    fn load_manifest(&self) -> Vec<ExternalPluginInfo> {
+        fs::read_to_string(self.manifest_path())
+            .ok()
+            .and_then(|s| serde_json::from_str(&s).ok())
+            .unwrap_or_default()
+    }
+
+    fn save_manifest(&self, plugins: &[ExternalPluginInfo]) -> AppResult<()> {
+        let json = serde_json::to_string_pretty(plugins).map_err(|e| {
+            AppError::with_details(
+                ErrorCode::InternalError,
+                "Failed to serialize external plugin manifest",
+                e.to_string(),
+            )
+        })?;
+        fs::write(self.manifest_path(), json)?;
+        Ok(())
+    }
+
+    /// Copies `source_path` into the plugins directory, spawns it once to
+    /// perform the handshake (failing the install if it doesn't declare a
+    /// valid signature), and registers it.
+    pub fn install_plugin(&self, source_path: &str) -> AppResult<ExternalPluginInfo> {
+        let source = Path::new(source_path);
+        let file_name = source
+            .file_name()
+            .ok_or_else(|| AppError::new(ErrorCode::InvalidInput, "Plugin path has no file name"))?;
+
+        let dest_path = self.plugins_dir.join(file_name);
+        fs::copy(source, &dest_path)?;
+
+        let name = file_name.to_string_lossy().to_string();
+        let plugin = ExternalPlugin::spawn(&name, &dest_path)?;
+        let info = ExternalPluginInfo {
+            name: plugin.name().to_string(),
+            binary_path: dest_path.to_string_lossy().to_string(),
+            signature: plugin.signature().clone(),
+        };
+
+        let mut plugins = self.load_manifest();
+        plugins.retain(|p| p.name != info.name);
+        plugins.push(info.clone());
+        self.save_manifest(&plugins)?;
+
+        Ok(info)
+    }
+
+    pub fn list_plugins(&self) -> Vec<ExternalPluginInfo> {
+        self.load_manifest()
+    }
+
+    pub fn remove_plugin(&self, name: &str) -> AppResult<()> {
+        let mut plugins = self.load_manifest();
+
+        if let Some(pos) = plugins.iter().position(|p| p.name == name) {
+            let plugin = plugins.remove(pos);
+            let _ = fs::remove_file(&plugin.binary_path);
+            self.save_manifest(&plugins)?;
+        }
+
+        Ok(())
+    }
+
+    /// Spawns every installed plugin, skipping (and logging) any that fail
+    /// to start or handshake rather than failing the whole batch -- one
+    /// crashed plugin shouldn't take every other provider down with it.
+    pub fn spawn_all(&self) -> Vec<ExternalPlugin> {
+        self.load_manifest()
+            .into_iter()
+            .filter_map(|info| match ExternalPlugin::spawn(&info.name, Path::new(&info.binary_path)) {
+                Ok(plugin) => Some(plugin),
+                Err(e) => {
+                    log::warn!("Failed to start external plugin '{}': {}", info.name, e);
+                    None
+                }
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,76 @@
+use crate::error::{AppError, AppResult, ErrorCode};
+use crate::utils::path_utils;
+use dashmap::DashMap;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+/// A cached OCR+grammar result for one file, invalidated by `(size,
+/// modified_date)` rather than content hashing — cheap enough to check on
+/// every batch run via a single `fs::metadata` call.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CacheEntry {
+    pub path: String,
+    pub size: u64,
+    pub modified_date: u64,
+    pub original_text: String,
+    pub corrected_text: String,
+    pub grammar_error_count: usize,
+    pub ocr_confidence: f32,
+}
+
+/// Persists batch extraction results across runs, keyed by file path, so
+/// re-running a batch over an unchanged directory skips OCR/grammar work
+/// entirely for files that haven't changed since the last run.
+pub struct ExtractionCache {
+    cache_file: PathBuf,
+}
+
+impl ExtractionCache {
+    pub fn new(app_data_dir: &Path) -> AppResult<Self> {
+        let cache_dir = app_data_dir.join("cache");
+        path_utils::ensure_directory_exists(&cache_dir.to_string_lossy())?;
+        Ok(Self {
+            cache_file: cache_dir.join("extraction_cache.json"),
+        })
+    }
+
+    /// Loads the persisted cache into an in-memory `DashMap` so concurrent
+    /// batch tasks can read/write it without an external lock.
+    pub fn load(&self) -> DashMap<String, CacheEntry> {
+        fs::read_to_string(&self.cache_file)
+            .ok()
+            .and_then(|s| serde_json::from_str::<Vec<CacheEntry>>(&s).ok())
+            .map(|entries| entries.into_iter().map(|e| (e.path.clone(), e)).collect())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self, entries: &DashMap<String, CacheEntry>) -> AppResult<()> {
+        let snapshot: Vec<CacheEntry> = entries.iter().map(|e| e.value().clone()).collect();
+        let json = serde_json::to_string_pretty(&snapshot).map_err(|e| {
+            AppError::with_details(
+                ErrorCode::InternalError,
+                "Failed to serialize extraction cache",
+                e.to_string(),
+            )
+        })?;
+        fs::write(&self.cache_file, json)?;
+        Ok(())
+    }
+
+    /// Stats `path`, returning the `(size, modified_date)` fingerprint used
+    /// to validate a cache entry without re-reading the file's contents.
+    pub fn fingerprint(path: &str) -> AppResult<(u64, u64)> {
+        let metadata = fs::metadata(path)?;
+        let size = metadata.len();
+        let modified_date = metadata
+            .modified()
+            .ok()
+            .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+
+        Ok((size, modified_date))
+    }
+}
@@ -1,6 +1,7 @@
 use crate::error::{AppResult, AppError, ErrorCode};
+use crate::services::video_frames::{self, FrameExtractionBackend, FrameExtractionOptions};
 use crate::utils::file_extensions::SupportedExtensions;
-use crate::utils::file_validation;
+use crate::utils::file_validation::{self, DetectedFormat};
 
 use crate::utils::path_utils;
 use serde::{Deserialize, Serialize};
@@ -47,6 +48,17 @@ pub struct VideoFrameExtractionResult {
     pub success: bool,
     pub error_message: Option<String>,
     pub metadata: std::collections::HashMap<String, serde_json::Value>,
+    /// Source timestamp of each `frame_paths` entry, in seconds; empty when
+    /// the backend that produced this result doesn't report timestamps.
+    #[serde(default)]
+    pub frame_timestamps_seconds: Vec<f64>,
+}
+
+/// One extracted video frame paired with the timestamp it was sampled from.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExtractedFrame {
+    pub path: String,
+    pub timestamp_seconds: f64,
 }
 
 pub struct FileHandlerService {
@@ -128,7 +140,7 @@ impl FileHandlerService {
         file_validation::validate_file_path(file_path)
     }
 
-    fn determine_file_type(extension: &str) -> FileType {
+    pub(crate) fn determine_file_type(extension: &str) -> FileType {
         if SupportedExtensions::IMAGE_EXTENSIONS.contains(&extension) {
             FileType::Image
         } else if SupportedExtensions::VIDEO_EXTENSIONS.contains(&extension) {
@@ -146,15 +158,43 @@ impl FileHandlerService {
         video_path: &str,
         output_dir: &str,
         frame_interval: Option<u32>,
-    ) -> AppResult<Vec<String>> {
+        backend: Option<FrameExtractionBackend>,
+    ) -> AppResult<Vec<ExtractedFrame>> {
         path_utils::ensure_directory_exists(output_dir)?;
-
-        let service = Self::get_default_instance();
-        let result = service.call_python_video_frame_service(video_path, output_dir, frame_interval).await?;
+        crate::services::MediaLimits::default().check_video(video_path, frame_interval)?;
+
+        let result = match backend.unwrap_or_default() {
+            FrameExtractionBackend::Python => {
+                let service = Self::get_default_instance();
+                service
+                    .call_python_video_frame_service(video_path, output_dir, frame_interval)
+                    .await?
+            }
+            FrameExtractionBackend::NativeFfmpeg => {
+                let mut options = FrameExtractionOptions::default();
+                if let Some(frame_interval) = frame_interval {
+                    options.frame_interval = frame_interval;
+                }
+                video_frames::extract_frames_native(video_path, output_dir, &options)?
+            }
+        };
 
         if result.success {
             log::info!("Successfully extracted {} frames from video", result.frame_paths.len());
-            Ok(result.frame_paths)
+            let frames = result
+                .frame_paths
+                .into_iter()
+                .enumerate()
+                .map(|(i, path)| ExtractedFrame {
+                    path,
+                    timestamp_seconds: result
+                        .frame_timestamps_seconds
+                        .get(i)
+                        .copied()
+                        .unwrap_or(0.0),
+                })
+                .collect();
+            Ok(frames)
         } else {
             Err(AppError::new(
                 ErrorCode::InternalError,
@@ -167,7 +207,8 @@ impl FileHandlerService {
         video_path: &str,
         output_dir: &str,
         frame_interval: Option<u32>,
-    ) -> AppResult<Vec<String>> {
+        backend: Option<FrameExtractionBackend>,
+    ) -> AppResult<Vec<ExtractedFrame>> {
         // Synchronous wrapper for the async function
         let rt = tokio::runtime::Runtime::new().map_err(|e| AppError::with_details(
             ErrorCode::InternalError,
@@ -175,7 +216,7 @@ impl FileHandlerService {
             e.to_string()
         ))?;
 
-        rt.block_on(Self::extract_frames_from_video(video_path, output_dir, frame_interval))
+        rt.block_on(Self::extract_frames_from_video(video_path, output_dir, frame_interval, backend))
     }
 
 
@@ -290,6 +331,180 @@ impl FileHandlerService {
         Ok(result)
     }
 
+    /// Unpacks `archive_path` (`.zip`, `.tar`, `.tar.gz`/`.tgz`) into
+    /// `output_dir`, keeping only entries whose content sniffs as a
+    /// supported image format, so a batch of scanned pages shipped as a
+    /// single archive can go straight into the OCR pipeline without the
+    /// user manually unzipping it first.
+    pub fn extract_archive_images(archive_path: &str, output_dir: &str) -> AppResult<Vec<String>> {
+        path_utils::ensure_directory_exists(output_dir)?;
+
+        let name = Path::new(archive_path)
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or("")
+            .to_lowercase();
+
+        if name.ends_with(".zip") {
+            Self::extract_zip_images(archive_path, output_dir)
+        } else if name.ends_with(".tar.gz") || name.ends_with(".tgz") {
+            Self::extract_tar_images(archive_path, output_dir, true)
+        } else if name.ends_with(".tar") {
+            Self::extract_tar_images(archive_path, output_dir, false)
+        } else {
+            Err(AppError::new(
+                ErrorCode::InvalidFileFormat,
+                format!("Unsupported archive format: {}", archive_path),
+            ))
+        }
+    }
+
+    fn extract_zip_images(archive_path: &str, output_dir: &str) -> AppResult<Vec<String>> {
+        let file = fs::File::open(archive_path)?;
+        let mut archive = zip::ZipArchive::new(file).map_err(|e| {
+            AppError::with_details(
+                ErrorCode::InvalidFileFormat,
+                "Failed to read zip archive",
+                e.to_string(),
+            )
+        })?;
+
+        let mut image_paths = Vec::new();
+        for i in 0..archive.len() {
+            let mut entry = archive.by_index(i).map_err(|e| {
+                AppError::with_details(
+                    ErrorCode::InvalidFileFormat,
+                    "Failed to read zip entry",
+                    e.to_string(),
+                )
+            })?;
+
+            if entry.is_dir() {
+                continue;
+            }
+
+            let Some(entry_name) = entry.enclosed_name().map(|p| p.to_path_buf()) else {
+                continue;
+            };
+            let dest_path = Self::safe_archive_join(Path::new(output_dir), &entry_name.to_string_lossy())?;
+
+            if let Some(parent) = dest_path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            let mut out_file = fs::File::create(&dest_path)?;
+            std::io::copy(&mut entry, &mut out_file)?;
+            drop(out_file);
+
+            if Self::is_supported_image_content(&dest_path) {
+                image_paths.push(dest_path.to_string_lossy().to_string());
+            } else {
+                let _ = fs::remove_file(&dest_path);
+            }
+        }
+
+        Ok(image_paths)
+    }
+
+    fn extract_tar_images(archive_path: &str, output_dir: &str, gzip: bool) -> AppResult<Vec<String>> {
+        let file = fs::File::open(archive_path)?;
+
+        if gzip {
+            Self::extract_tar_entries(tar::Archive::new(flate2::read::GzDecoder::new(file)), output_dir)
+        } else {
+            Self::extract_tar_entries(tar::Archive::new(file), output_dir)
+        }
+    }
+
+    fn extract_tar_entries<R: std::io::Read>(
+        mut archive: tar::Archive<R>,
+        output_dir: &str,
+    ) -> AppResult<Vec<String>> {
+        let entries = archive.entries().map_err(|e| {
+            AppError::with_details(
+                ErrorCode::InvalidFileFormat,
+                "Failed to read tar archive",
+                e.to_string(),
+            )
+        })?;
+
+        let mut image_paths = Vec::new();
+        for entry in entries {
+            let mut entry = entry.map_err(|e| {
+                AppError::with_details(
+                    ErrorCode::InvalidFileFormat,
+                    "Failed to read tar entry",
+                    e.to_string(),
+                )
+            })?;
+
+            if !entry.header().entry_type().is_file() {
+                continue;
+            }
+
+            let entry_path = entry
+                .path()
+                .map_err(|e| {
+                    AppError::with_details(
+                        ErrorCode::InvalidFileFormat,
+                        "Invalid tar entry path",
+                        e.to_string(),
+                    )
+                })?
+                .to_path_buf();
+            let dest_path = Self::safe_archive_join(Path::new(output_dir), &entry_path.to_string_lossy())?;
+
+            if let Some(parent) = dest_path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            let mut out_file = fs::File::create(&dest_path)?;
+            std::io::copy(&mut entry, &mut out_file)?;
+            drop(out_file);
+
+            if Self::is_supported_image_content(&dest_path) {
+                image_paths.push(dest_path.to_string_lossy().to_string());
+            } else {
+                let _ = fs::remove_file(&dest_path);
+            }
+        }
+
+        Ok(image_paths)
+    }
+
+    /// Joins `entry_name` onto `output_dir`, rejecting absolute paths or any
+    /// `..` component so a crafted archive ("zip slip") can't write outside
+    /// the destination directory.
+    fn safe_archive_join(output_dir: &Path, entry_name: &str) -> AppResult<std::path::PathBuf> {
+        let entry_path = Path::new(entry_name);
+        if entry_path.is_absolute()
+            || entry_path
+                .components()
+                .any(|c| matches!(c, std::path::Component::ParentDir))
+        {
+            return Err(AppError::new(
+                ErrorCode::InvalidFileFormat,
+                format!("Archive entry escapes the destination directory: {}", entry_name),
+            ));
+        }
+
+        Ok(output_dir.join(entry_path))
+    }
+
+    /// Sniffs the file just written to `path` and reports whether its
+    /// content (not just its name) matches a supported image format.
+    fn is_supported_image_content(path: &Path) -> bool {
+        matches!(
+            file_validation::detect_format(&path.to_string_lossy()),
+            Ok(Some(
+                DetectedFormat::Png
+                    | DetectedFormat::Jpeg
+                    | DetectedFormat::Bmp
+                    | DetectedFormat::Gif
+                    | DetectedFormat::WebP
+                    | DetectedFormat::Tiff
+            ))
+        )
+    }
+
     pub fn cleanup_temp_files(temp_dir: &str) -> AppResult<()> {
         let path = Path::new(temp_dir);
 
@@ -306,3 +521,119 @@ impl FileHandlerService {
         Ok(())
     }
 }
+
+/// Result of a cheap structural check — no full OCR/decode pass, just
+/// enough to catch truncated or malformed files before they waste time in
+/// the batch pipeline.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum IntegrityStatus {
+    Ok,
+    Broken { reason: String },
+}
+
+/// Pre-screens a file for structural corruption based on `determine_file_type`,
+/// so `process_single_file_batch` can short-circuit with a descriptive error
+/// instead of spending an OCR round-trip on a file that was never going to work.
+pub struct FileIntegrity;
+
+impl FileIntegrity {
+    pub fn check(file_path: &str) -> IntegrityStatus {
+        let extension = Path::new(file_path)
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .unwrap_or("")
+            .to_lowercase();
+
+        match FileHandlerService::determine_file_type(&extension) {
+            FileType::Image => Self::check_image(file_path),
+            FileType::Pdf => Self::check_pdf(file_path),
+            FileType::Video => Self::check_video(file_path),
+            // Documents are validated by the Python extraction service
+            // itself; nothing cheaper to check ahead of time here.
+            FileType::Document | FileType::Unknown => IntegrityStatus::Ok,
+        }
+    }
+
+    fn check_image(file_path: &str) -> IntegrityStatus {
+        match image::open(file_path) {
+            Ok(img) => {
+                use image::GenericImageView;
+                let (width, height) = img.dimensions();
+                if width == 0 || height == 0 {
+                    IntegrityStatus::Broken {
+                        reason: "image decoded with zero dimensions".to_string(),
+                    }
+                } else {
+                    IntegrityStatus::Ok
+                }
+            }
+            Err(e) => IntegrityStatus::Broken {
+                reason: format!("truncated or unreadable image: {}", e),
+            },
+        }
+    }
+
+    fn check_pdf(file_path: &str) -> IntegrityStatus {
+        let bytes = match fs::read(file_path) {
+            Ok(bytes) => bytes,
+            Err(e) => {
+                return IntegrityStatus::Broken {
+                    reason: format!("failed to read file: {}", e),
+                }
+            }
+        };
+
+        if !bytes.starts_with(b"%PDF-") {
+            return IntegrityStatus::Broken {
+                reason: "missing %PDF- header".to_string(),
+            };
+        }
+
+        // A well-formed PDF ends with a trailer pointing at an xref table;
+        // scanning the tail avoids parsing the whole object graph.
+        let tail_start = bytes.len().saturating_sub(2048);
+        let tail = String::from_utf8_lossy(&bytes[tail_start..]);
+
+        if !tail.contains("startxref") || !tail.contains("%%EOF") {
+            return IntegrityStatus::Broken {
+                reason: "invalid PDF xref/trailer".to_string(),
+            };
+        }
+
+        IntegrityStatus::Ok
+    }
+
+    fn check_video(file_path: &str) -> IntegrityStatus {
+        let mut header = [0u8; 16];
+        let bytes_read = match file_validation::read_header_bytes(file_path, &mut header) {
+            Ok(n) => n,
+            Err(e) => {
+                return IntegrityStatus::Broken {
+                    reason: format!("failed to read file: {}", e),
+                }
+            }
+        };
+
+        if bytes_read == 0 {
+            return IntegrityStatus::Broken {
+                reason: "file is empty".to_string(),
+            };
+        }
+
+        // Recognize the container magic bytes of the video formats we
+        // support; `file_validation::detect_format` doesn't sniff for MKV/WebM
+        // (EBML), so that signature is still checked directly here. Deeper
+        // stream probing happens at actual extraction time.
+        let is_known_container = &header[4..8] == b"ftyp"
+            || &header[..4] == b"RIFF"
+            || header[..4] == [0x1A, 0x45, 0xDF, 0xA3]; // Matroska/WebM (EBML)
+
+        if bytes_read >= 8 && !is_known_container {
+            return IntegrityStatus::Broken {
+                reason: "unrecognized video container".to_string(),
+            };
+        }
+
+        IntegrityStatus::Ok
+    }
+}
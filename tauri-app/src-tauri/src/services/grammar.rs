@@ -6,6 +6,16 @@ use std::sync::Arc;
 use dashmap::DashMap;
 use std::hash::{Hash, Hasher};
 use std::collections::hash_map::DefaultHasher;
+use futures::stream::{self, StreamExt};
+
+/// `check_batch` runs this many texts concurrently unless overridden via
+/// `GrammarConfig.batch_concurrency`. Defaults to the number of available
+/// cores, mirroring `batch_commands::default_max_concurrency`.
+fn default_batch_concurrency() -> usize {
+    std::thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(4)
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GrammarCheckResult {
@@ -30,7 +40,7 @@ pub struct GrammarError {
     pub error_type: ErrorType,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ErrorType {
     Spelling,
     Grammar,
@@ -41,16 +51,55 @@ pub enum ErrorType {
     Other,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum GrammarProvider {
     Harper,
     OfflineRules,
-    Hybrid,
+    Plugins,
+    LanguageTool,
 }
 
+/// One provider in `GrammarConfig.providers`'s priority list, mirroring how
+/// Helix lets you attach several language servers per language with
+/// `only-features`/`except-features`. `check_text` runs providers in order
+/// and, for every error span, keeps only the first provider's error whose
+/// filter admits its `ErrorType` -- so a provider placed later in the list
+/// acts as a fallback only for the categories earlier providers decline.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct GrammarConfig {
+pub struct ProviderEntry {
     pub provider: GrammarProvider,
+    /// If non-empty, this provider is only consulted for these error types.
+    #[serde(default)]
+    pub only_error_types: Vec<ErrorType>,
+    /// Error types this provider is never consulted for, even if
+    /// `only_error_types` would otherwise admit them.
+    #[serde(default)]
+    pub except_error_types: Vec<ErrorType>,
+}
+
+impl ProviderEntry {
+    /// A provider entry with no filtering: it's consulted for every error
+    /// type not already claimed by an earlier provider in the list.
+    pub fn unrestricted(provider: GrammarProvider) -> Self {
+        Self {
+            provider,
+            only_error_types: vec![],
+            except_error_types: vec![],
+        }
+    }
+
+    fn admits(&self, error_type: ErrorType) -> bool {
+        if self.except_error_types.contains(&error_type) {
+            return false;
+        }
+        self.only_error_types.is_empty() || self.only_error_types.contains(&error_type)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GrammarConfig {
+    /// Providers run in priority order; see `ProviderEntry`.
+    pub providers: Vec<ProviderEntry>,
     pub language: String,
     pub enable_style_checks: bool,
     pub enable_picky_rules: bool,
@@ -59,6 +108,56 @@ pub struct GrammarConfig {
     pub auto_apply_threshold: f32,
     pub realtime_checking: bool,
     pub smart_suggestions: bool,
+    pub language_tool_url: String,
+    /// Names of installed rule packs (see `services::rule_packs`) whose
+    /// custom words/ignore patterns should be merged into this checker.
+    pub rule_packs: Vec<String>,
+    /// Personal vocabulary (proper nouns, product names, domain terms)
+    /// merged into Harper's curated FST dictionary so these words stop
+    /// being flagged as `Spelling` errors.
+    pub custom_words: Vec<String>,
+    /// Optional path to a newline-delimited word list (`word` or
+    /// `word frequency`) merged into `custom_words` when building the
+    /// Harper dictionary.
+    pub custom_dictionary_path: Option<String>,
+    /// Regexes matched against each error's source text; any error whose
+    /// match falls inside one is dropped, regardless of provider.
+    pub ignore_patterns: Vec<String>,
+    /// How many texts `check_batch` checks concurrently.
+    #[serde(default = "default_batch_concurrency")]
+    pub batch_concurrency: usize,
+}
+
+// LanguageTool's `/v2/check` response shapes, deserialized just enough to
+// build our own `GrammarError`s from it.
+#[derive(Debug, Deserialize)]
+struct LanguageToolResponse {
+    matches: Vec<LanguageToolMatch>,
+}
+
+#[derive(Debug, Deserialize)]
+struct LanguageToolMatch {
+    message: String,
+    offset: usize,
+    length: usize,
+    replacements: Vec<LanguageToolReplacement>,
+    rule: LanguageToolRule,
+}
+
+#[derive(Debug, Deserialize)]
+struct LanguageToolReplacement {
+    value: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct LanguageToolRule {
+    id: String,
+    category: LanguageToolCategory,
+}
+
+#[derive(Debug, Deserialize)]
+struct LanguageToolCategory {
+    id: String,
 }
 
 // Harper-specific structures are handled internally by harper-core
@@ -66,11 +165,32 @@ pub struct GrammarConfig {
 
 pub struct GrammarService {
     config: GrammarConfig,
+    http_client: reqwest::Client,
+    rule_pack_contents: crate::services::rule_packs::RulePackContents,
+    plugin_providers: Arc<Vec<crate::services::grammar_plugins::WasmGrammarProvider>>,
     cache: Arc<DashMap<String, (GrammarCheckResult, Instant)>>,
     suggestion_cache: Arc<DashMap<String, Vec<String>>>,
     performance_stats: Arc<DashMap<String, PerformanceMetrics>>,
+    /// Merged Harper dictionaries keyed by their sorted custom-word list, so
+    /// the same personal vocabulary isn't re-merged into the curated FST on
+    /// every `check_text` call.
+    harper_dictionary_cache: Arc<DashMap<String, Arc<harper_core::spell::MergedDictionary>>>,
+    /// The last document seen by `check_text_incremental`, keyed by
+    /// `INCREMENTAL_DOC_KEY`, used to diff edits instead of re-linting the
+    /// whole text on every keystroke.
+    last_document: Arc<DashMap<String, (String, Vec<GrammarError>)>>,
 }
 
+/// Single-slot key `last_document` is stored under; the realtime-checking
+/// path tracks one active document per `GrammarService`.
+const INCREMENTAL_DOC_KEY: &str = "current";
+
+/// Wall-clock ceiling on a single WASM plugin `check()` call, enforced via
+/// `tokio::time::timeout` around the `spawn_blocking` task that runs it.
+/// Backstops `PLUGIN_FUEL_LIMIT` for anything fuel doesn't cover (e.g. a
+/// stall during instantiation).
+const PLUGIN_CALL_TIMEOUT: Duration = Duration::from_secs(2);
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PerformanceMetrics {
     pub total_checks: u64,
@@ -99,7 +219,7 @@ pub struct LanguageStats {
 impl Default for GrammarConfig {
     fn default() -> Self {
         Self {
-            provider: GrammarProvider::Harper, // Use Harper as the primary provider
+            providers: vec![ProviderEntry::unrestricted(GrammarProvider::Harper)],
             language: "en-US".to_string(),
             enable_style_checks: true,
             enable_picky_rules: false,
@@ -108,6 +228,12 @@ impl Default for GrammarConfig {
             auto_apply_threshold: 0.9,
             realtime_checking: true,
             smart_suggestions: true,
+            language_tool_url: "https://api.languagetool.org".to_string(),
+            rule_packs: vec![],
+            custom_words: vec![],
+            custom_dictionary_path: None,
+            ignore_patterns: vec![],
+            batch_concurrency: default_batch_concurrency(),
         }
     }
 }
@@ -118,18 +244,195 @@ impl GrammarService {
     }
 
     pub fn with_config(config: GrammarConfig) -> Self {
+        let rule_pack_contents = Self::load_rule_pack_contents(&config.rule_packs);
+        let plugin_providers = Arc::new(Self::load_grammar_plugins());
+
         Self {
             config,
+            http_client: reqwest::Client::builder()
+                .timeout(Duration::from_secs(15))
+                .build()
+                .unwrap_or_else(|_| reqwest::Client::new()),
+            rule_pack_contents,
+            plugin_providers,
             cache: Arc::new(DashMap::new()),
             suggestion_cache: Arc::new(DashMap::new()),
             performance_stats: Arc::new(DashMap::new()),
+            harper_dictionary_cache: Arc::new(DashMap::new()),
+            last_document: Arc::new(DashMap::new()),
+        }
+    }
+
+    /// Loads every installed WASM grammar plugin from the app data
+    /// directory, mirroring `load_rule_pack_contents`'s resolution of
+    /// `APP_DATA_DIR`.
+    fn load_grammar_plugins() -> Vec<crate::services::grammar_plugins::WasmGrammarProvider> {
+        let app_data_dir = std::env::var("APP_DATA_DIR")
+            .map(std::path::PathBuf::from)
+            .unwrap_or_else(|_| std::env::temp_dir().join("text-recognize"));
+
+        match crate::services::grammar_plugins::GrammarPluginManager::new(&app_data_dir) {
+            Ok(manager) => manager.load_providers(),
+            Err(e) => {
+                log::warn!("Failed to load grammar plugins: {}", e);
+                vec![]
+            }
+        }
+    }
+
+    /// Runs every loaded plugin against `text`, mapping each plugin's
+    /// declared `error_type` string onto `ErrorType` (defaulting to `Other`
+    /// for unrecognized tags) and tagging the rule id with the plugin name
+    /// so overlapping plugins can be told apart.
+    ///
+    /// Each plugin's `check()` runs on a `spawn_blocking` thread under a
+    /// `PLUGIN_CALL_TIMEOUT` so a stalled or fuel-exhausting plugin blocks
+    /// neither the calling Tokio worker nor the `GrammarState` lock held by
+    /// `run_lint_pipeline`'s caller.
+    async fn check_with_plugins(&self, text: &str) -> Vec<GrammarError> {
+        let char_byte_table = Self::build_char_byte_table(text);
+        let language = self.config.language.clone();
+        let mut errors = Vec::new();
+
+        for idx in 0..self.plugin_providers.len() {
+            let providers = self.plugin_providers.clone();
+            let text_owned = text.to_string();
+            let language = language.clone();
+
+            let outcome = tokio::time::timeout(
+                PLUGIN_CALL_TIMEOUT,
+                tokio::task::spawn_blocking(move || {
+                    let provider = &providers[idx];
+                    (provider.name().to_string(), provider.check(&text_owned, &language))
+                }),
+            )
+            .await;
+
+            let (provider_name, check_result) = match outcome {
+                Ok(Ok(pair)) => pair,
+                Ok(Err(join_err)) => {
+                    log::warn!("Grammar plugin task panicked: {}", join_err);
+                    continue;
+                }
+                Err(_) => {
+                    log::warn!(
+                        "Grammar plugin '{}' exceeded its time limit",
+                        self.plugin_providers[idx].name()
+                    );
+                    continue;
+                }
+            };
+
+            match check_result {
+                Ok(raw_errors) => errors.extend(raw_errors.into_iter().map(|raw| GrammarError {
+                    message: raw.message,
+                    rule_id: format!("plugin:{}", provider_name),
+                    category: provider_name.clone(),
+                    offset: raw.offset,
+                    length: raw.length,
+                    context: Self::extract_context(text, &char_byte_table, raw.offset, raw.length),
+                    suggestions: raw.suggestions,
+                    severity: "warning".to_string(),
+                    confidence: 0.7,
+                    error_type: match raw.error_type.as_str() {
+                        "spelling" => ErrorType::Spelling,
+                        "grammar" => ErrorType::Grammar,
+                        "punctuation" => ErrorType::Punctuation,
+                        "style" => ErrorType::Style,
+                        "redundancy" => ErrorType::Redundancy,
+                        "clarity" => ErrorType::Clarity,
+                        _ => ErrorType::Other,
+                    },
+                })),
+                Err(e) => log::warn!("Grammar plugin '{}' failed: {}", provider_name, e),
+            }
+        }
+
+        errors
+    }
+
+    fn load_rule_pack_contents(rule_packs: &[String]) -> crate::services::rule_packs::RulePackContents {
+        if rule_packs.is_empty() {
+            return crate::services::rule_packs::RulePackContents::default();
+        }
+
+        let app_data_dir = std::env::var("APP_DATA_DIR")
+            .map(std::path::PathBuf::from)
+            .unwrap_or_else(|_| std::env::temp_dir().join("text-recognize"));
+
+        match crate::services::rule_packs::RulePackManager::new(&app_data_dir) {
+            Ok(manager) => manager.load_contents(rule_packs),
+            Err(e) => {
+                log::warn!("Failed to load rule packs: {}", e);
+                crate::services::rule_packs::RulePackContents::default()
+            }
+        }
+    }
+
+    /// Collects the user's personal vocabulary: `GrammarConfig.custom_words`,
+    /// any installed rule packs' words, and (if set) the contents of
+    /// `custom_dictionary_path`, one word per line with an optional
+    /// trailing frequency column that's ignored. Sorted and deduplicated so
+    /// the result is stable to use as a dictionary cache key.
+    fn collect_custom_words(&self) -> Vec<String> {
+        let mut words: Vec<String> = Vec::new();
+        words.extend(self.config.custom_words.iter().cloned());
+        words.extend(self.rule_pack_contents.custom_words.iter().cloned());
+
+        if let Some(path) = &self.config.custom_dictionary_path {
+            match std::fs::read_to_string(path) {
+                Ok(contents) => words.extend(
+                    contents
+                        .lines()
+                        .filter_map(|line| line.split_whitespace().next())
+                        .map(|word| word.to_string()),
+                ),
+                Err(e) => log::warn!("Failed to read custom dictionary '{}': {}", path, e),
+            }
         }
+
+        words.sort();
+        words.dedup();
+        words
+    }
+
+    /// Builds (or returns the cached) Harper dictionary: the curated FST
+    /// merged with the user's personal vocabulary via `MergedDictionary`,
+    /// cached by the sorted word list so it's only rebuilt when that list
+    /// actually changes.
+    fn harper_dictionary(&self) -> Arc<harper_core::spell::MergedDictionary> {
+        use harper_core::spell::{FstDictionary, MergedDictionary, MutableDictionary};
+
+        let custom_words = self.collect_custom_words();
+        let cache_key = custom_words.join("\n");
+
+        if let Some(cached) = self.harper_dictionary_cache.get(&cache_key) {
+            return cached.clone();
+        }
+
+        let mut merged = MergedDictionary::new();
+        merged.add_dictionary(FstDictionary::curated());
+
+        if !custom_words.is_empty() {
+            let mut mutable = MutableDictionary::new();
+            for word in &custom_words {
+                mutable.append_word_str(word, harper_core::spell::WordMetadata::default());
+            }
+            merged.add_dictionary(Arc::new(mutable));
+        }
+
+        let merged = Arc::new(merged);
+        self.harper_dictionary_cache.insert(cache_key, merged.clone());
+        merged
     }
 
     // Keep compatibility with existing code that expects this method
-    pub fn with_custom_server(_server_url: String) -> Self {
-        // Harper doesn't use custom servers, so just return default config
-        Self::with_config(GrammarConfig::default())
+    pub fn with_custom_server(server_url: String) -> Self {
+        Self::with_config(GrammarConfig {
+            providers: vec![ProviderEntry::unrestricted(GrammarProvider::LanguageTool)],
+            language_tool_url: server_url,
+            ..GrammarConfig::default()
+        })
     }
 
     pub fn with_harper_config(config: GrammarConfig) -> Self {
@@ -168,66 +471,243 @@ impl GrammarService {
 
         let start_time = Instant::now();
 
-        let errors = match self.config.provider {
-            GrammarProvider::Harper => {
-                self.check_with_harper(text).unwrap_or_else(|e| {
+        let errors = self.run_lint_pipeline(text).await;
+        let corrected_text = self.apply_corrections(text, &errors, auto_correct);
+
+        let processing_time = start_time.elapsed().as_secs_f64();
+
+        let result = GrammarCheckResult {
+            original_text: text.to_string(),
+            corrected_text,
+            errors: errors.clone(),
+            processing_time,
+            error_count: errors.len(),
+        };
+
+        // Cache the result and update performance metrics
+        self.cache.insert(cache_key, (result.clone(), Instant::now()));
+        self.update_performance_stats("check_completed", processing_time);
+
+        // Limit cache size
+        if self.cache.len() > 100 {
+            self.cleanup_cache();
+        }
+
+        Ok(result)
+    }
+
+    /// Runs every configured provider over `text` in priority order and
+    /// applies the rule-pack / custom-word filters. Shared by `check_text`
+    /// (over the whole document) and `check_text_incremental` (over just
+    /// the sentences touching an edit).
+    async fn run_lint_pipeline(&self, text: &str) -> Vec<GrammarError> {
+        // Run providers in priority order. For every text span (identified by
+        // offset+length), the first provider whose filter admits the error's
+        // `ErrorType` wins; later providers only fill in categories earlier
+        // ones declined or didn't flag at all.
+        let mut errors: Vec<GrammarError> = Vec::new();
+        for provider_entry in &self.config.providers {
+            let candidate_errors = match provider_entry.provider {
+                GrammarProvider::Harper => self.check_with_harper(text).unwrap_or_else(|e| {
                     log::warn!("Harper failed: {}", e);
                     vec![]
-                })
-            }
-            GrammarProvider::OfflineRules => {
-                // Simple offline rules - just basic checks
-                self.check_basic_patterns(text)
-            }
-            GrammarProvider::Hybrid => {
-                let mut all_errors = self.check_basic_patterns(text);
-
-                // Try to enhance with Harper
-                if let Ok(harper_errors) = self.check_with_harper(text) {
-                    // Merge errors, avoiding duplicates
-                    for harper_error in harper_errors {
-                        if !all_errors.iter().any(|e|
-                            e.offset == harper_error.offset &&
-                            e.length == harper_error.length
-                        ) {
-                            all_errors.push(harper_error);
-                        }
-                    }
+                }),
+                GrammarProvider::OfflineRules => self.check_basic_patterns(text),
+                GrammarProvider::Plugins => self.check_with_plugins(text).await,
+                GrammarProvider::LanguageTool => {
+                    self.check_with_language_tool(text).await.unwrap_or_else(|e| {
+                        log::warn!("LanguageTool check failed: {}", e);
+                        vec![]
+                    })
                 }
+            };
 
-                all_errors
+            for error in candidate_errors {
+                if !provider_entry.admits(error.error_type) {
+                    continue;
+                }
+                if errors
+                    .iter()
+                    .any(|e| e.offset == error.offset && e.length == error.length)
+                {
+                    continue;
+                }
+                errors.push(error);
             }
-        };
+        }
+
+        let errors = self.apply_rule_pack_filters(text, errors);
+        self.apply_custom_word_filters(text, errors)
+    }
 
-        // Apply corrections if requested or if smart auto-correction is enabled
-        let corrected_text = if auto_correct && !errors.is_empty() {
-            self.apply_smart_corrections(text, &errors)
+    /// Applies corrections if requested or if smart auto-correction is
+    /// enabled, else returns `text` unchanged.
+    fn apply_corrections(&self, text: &str, errors: &[GrammarError], auto_correct: bool) -> String {
+        if auto_correct && !errors.is_empty() {
+            self.apply_smart_corrections(text, errors)
         } else if self.config.auto_apply_high_confidence && !errors.is_empty() {
-            self.apply_high_confidence_corrections(text, &errors)
+            self.apply_high_confidence_corrections(text, errors)
         } else {
             text.to_string()
+        }
+    }
+
+    /// Incremental variant of `check_text` for the `realtime_checking`
+    /// path. Instead of re-linting the whole document on every keystroke,
+    /// diffs `text` against the last document checked on this service,
+    /// discards cached errors overlapping the changed byte range, shifts
+    /// the ones past it by the length delta, and re-lints only the
+    /// sentences touching the edit (expanded via
+    /// `expand_to_sentence_bounds`), splicing the fresh errors back in at
+    /// their absolute offsets. Falls back to a full `check_text` when
+    /// there's no prior document to diff against, or the changed range
+    /// covers more than half the document -- the anchored-diagnostic
+    /// approach degrades to a full re-check rather than tracking an
+    /// unbounded edit.
+    pub async fn check_text_incremental(&self, text: &str) -> Result<GrammarCheckResult> {
+        let previous = self
+            .last_document
+            .get(INCREMENTAL_DOC_KEY)
+            .map(|entry| entry.value().clone());
+
+        let Some((old_text, old_errors)) = previous else {
+            let result = self.check_text(text, false).await?;
+            self.last_document
+                .insert(INCREMENTAL_DOC_KEY.to_string(), (text.to_string(), result.errors.clone()));
+            return Ok(result);
         };
 
+        if old_text == text {
+            return Ok(GrammarCheckResult {
+                original_text: text.to_string(),
+                corrected_text: text.to_string(),
+                error_count: old_errors.len(),
+                errors: old_errors,
+                processing_time: 0.0,
+            });
+        }
+
+        let (lo, old_hi, new_hi) = Self::diff_changed_range(&old_text, text);
+        let changed_len = old_hi - lo;
+
+        // Anchoring a diff that touches more than half the document isn't
+        // worth it -- just re-lint everything.
+        if changed_len * 2 > old_text.len().max(1) {
+            let result = self.check_text(text, false).await?;
+            self.last_document
+                .insert(INCREMENTAL_DOC_KEY.to_string(), (text.to_string(), result.errors.clone()));
+            return Ok(result);
+        }
+
+        let start_time = Instant::now();
+        let delta = new_hi as isize - old_hi as isize;
+
+        // Keep errors entirely before the changed range untouched, and shift
+        // errors entirely after it by the length delta; drop anything that
+        // overlaps the edit, since its span is no longer meaningful.
+        let mut errors: Vec<GrammarError> = old_errors
+            .into_iter()
+            .filter_map(|mut error| {
+                let error_end = error.offset + error.length;
+                if error_end <= lo {
+                    Some(error)
+                } else if error.offset >= old_hi {
+                    error.offset = (error.offset as isize + delta) as usize;
+                    Some(error)
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        let (chunk_start, chunk_end) = Self::expand_to_sentence_bounds(text, lo, new_hi);
+        let mut fresh_errors = self.run_lint_pipeline(&text[chunk_start..chunk_end]).await;
+        let char_byte_table = Self::build_char_byte_table(text);
+        for error in &mut fresh_errors {
+            error.offset += chunk_start;
+            error.context = Self::extract_context(text, &char_byte_table, error.offset, error.length);
+        }
+
+        // `expand_to_sentence_bounds` widens the re-lint window out to
+        // sentence boundaries, which usually reaches back before `lo` and/or
+        // past `new_hi` into text the kept buckets above already cover.
+        // Drop any fresh error whose span overlaps one we kept, so the
+        // overlap isn't reported twice.
+        let kept_spans: Vec<(usize, usize)> =
+            errors.iter().map(|e| (e.offset, e.offset + e.length)).collect();
+        fresh_errors.retain(|fresh| {
+            let fresh_end = fresh.offset + fresh.length;
+            !kept_spans
+                .iter()
+                .any(|&(start, end)| fresh.offset < end && start < fresh_end)
+        });
+
+        errors.extend(fresh_errors);
+        errors.sort_by_key(|e| e.offset);
+
+        let corrected_text = self.apply_corrections(text, &errors, false);
         let processing_time = start_time.elapsed().as_secs_f64();
 
         let result = GrammarCheckResult {
             original_text: text.to_string(),
             corrected_text,
+            error_count: errors.len(),
             errors: errors.clone(),
             processing_time,
-            error_count: errors.len(),
         };
 
-        // Cache the result and update performance metrics
-        self.cache.insert(cache_key, (result.clone(), Instant::now()));
-        self.update_performance_stats("check_completed", processing_time);
+        self.last_document
+            .insert(INCREMENTAL_DOC_KEY.to_string(), (text.to_string(), errors));
+        self.cache
+            .insert(self.generate_cache_key(text, false), (result.clone(), Instant::now()));
 
-        // Limit cache size
-        if self.cache.len() > 100 {
-            self.cleanup_cache();
+        Ok(result)
+    }
+
+    /// Finds the common-prefix/suffix boundaries of an edit, in bytes, so
+    /// that `old[lo..old_hi]` is the minimal contiguous region that changed
+    /// into `new[lo..new_hi]`.
+    fn diff_changed_range(old: &str, new: &str) -> (usize, usize, usize) {
+        let old_chars: Vec<(usize, char)> = old.char_indices().collect();
+        let new_chars: Vec<(usize, char)> = new.char_indices().collect();
+
+        let mut prefix = 0;
+        while prefix < old_chars.len()
+            && prefix < new_chars.len()
+            && old_chars[prefix].1 == new_chars[prefix].1
+        {
+            prefix += 1;
         }
 
-        Ok(result)
+        let max_suffix = (old_chars.len() - prefix).min(new_chars.len() - prefix);
+        let mut suffix = 0;
+        while suffix < max_suffix
+            && old_chars[old_chars.len() - 1 - suffix].1 == new_chars[new_chars.len() - 1 - suffix].1
+        {
+            suffix += 1;
+        }
+
+        let lo = old_chars.get(prefix).map(|(b, _)| *b).unwrap_or(old.len());
+        let old_hi = if suffix == 0 { old.len() } else { old_chars[old_chars.len() - suffix].0 };
+        let new_hi = if suffix == 0 { new.len() } else { new_chars[new_chars.len() - suffix].0 };
+
+        (lo, old_hi, new_hi)
+    }
+
+    /// Expands `[lo, hi)` outward to the nearest enclosing sentence
+    /// boundaries in `text`, using the same `.!?` splitting as
+    /// `get_language_stats`, so a re-lint only has to cover the sentences
+    /// actually touched by the edit.
+    fn expand_to_sentence_bounds(text: &str, lo: usize, hi: usize) -> (usize, usize) {
+        const SENTENCE_ENDS: &[char] = &['.', '!', '?'];
+
+        let start = text[..lo].rfind(SENTENCE_ENDS).map(|i| i + 1).unwrap_or(0);
+        let end = text[hi..]
+            .find(SENTENCE_ENDS)
+            .map(|i| hi + i + 1)
+            .unwrap_or(text.len());
+
+        (start, end)
     }
 
     fn cleanup_cache(&self) {
@@ -302,15 +782,50 @@ impl GrammarService {
         Ok(corrected_text)
     }
 
+    /// Two-phase entry point: runs the full check (populating the cache with
+    /// suggestions) but strips suggestions from the returned errors so the
+    /// caller only pays for span + message, not replacement generation.
+    pub async fn check_text_lazy(&self, text: &str) -> Result<GrammarCheckResult> {
+        let mut result = self.check_text(text, false).await?;
+        for error in &mut result.errors {
+            error.suggestions.clear();
+        }
+        Ok(result)
+    }
+
+    /// Fills in replacement suggestions for a single error from a previous
+    /// `check_text_lazy` call, reusing the cached full analysis rather than
+    /// re-running the engine.
+    pub async fn resolve_correction(&self, text: &str, error_index: usize) -> Result<Vec<String>> {
+        let cache_key = self.generate_cache_key(text, false);
+
+        let cached_errors = self.cache.get(&cache_key).map(|entry| entry.value().0.errors.clone());
+
+        let errors = match cached_errors {
+            Some(errors) => errors,
+            None => self.check_text(text, false).await?.errors,
+        };
+
+        Ok(errors
+            .get(error_index)
+            .map(|error| error.suggestions.clone())
+            .unwrap_or_default())
+    }
+
+    /// Checks every text concurrently, bounded by `GrammarConfig.batch_concurrency`,
+    /// preserving input order in the returned results. The shared `cache`
+    /// (and the other `DashMap` caches `check_text` touches) is safe to hit
+    /// from the concurrent tasks since `DashMap` shards its locking.
     pub async fn check_batch(&self, texts: Vec<String>, auto_correct: bool) -> Result<BatchGrammarResult> {
         let start_time = Instant::now();
-        let mut results = Vec::new();
 
-        // Process texts in parallel for better performance
-        for text in texts {
-            let result = self.check_text(&text, auto_correct).await?;
-            results.push(result);
-        }
+        let results: Vec<GrammarCheckResult> = stream::iter(texts)
+            .map(|text| async move { self.check_text(&text, auto_correct).await })
+            .buffered(self.config.batch_concurrency.max(1))
+            .collect::<Vec<_>>()
+            .await
+            .into_iter()
+            .collect::<Result<Vec<_>>>()?;
 
         let total_processing_time = start_time.elapsed().as_secs_f64();
 
@@ -325,8 +840,14 @@ impl GrammarService {
         let mut hasher = DefaultHasher::new();
         text.trim().hash(&mut hasher);
         auto_correct.hash(&mut hasher);
-        self.config.smart_suggestions.hash(&mut hasher);
-        self.config.enable_style_checks.hash(&mut hasher);
+        // `GrammarConfig` can't derive `Hash` (it holds an `f32`), so hash
+        // its serialized form instead. This keys the cache off every field
+        // -- providers, rule packs, custom words/dictionary, ignore
+        // patterns, language, etc. -- not just a hand-picked subset, so
+        // changing any of them invalidates stale cached results.
+        serde_json::to_string(&self.config)
+            .unwrap_or_default()
+            .hash(&mut hasher);
         format!("{:x}", hasher.finish())
     }
 
@@ -472,15 +993,74 @@ impl GrammarService {
         }
     }
 
+    async fn check_with_language_tool(&self, text: &str) -> Result<Vec<GrammarError>> {
+        let response = self.http_client
+            .post(&format!("{}/v2/check", self.config.language_tool_url))
+            .form(&[
+                ("text", text),
+                ("language", &self.config.language),
+            ])
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            let error_text = response.text().await.unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(anyhow::anyhow!("LanguageTool returned error: {}", error_text));
+        }
+
+        let lt_response: LanguageToolResponse = response.json().await?;
+        let char_byte_table = Self::build_char_byte_table(text);
+
+        let errors = lt_response.matches.into_iter().map(|m| {
+            let (error_type, confidence) = self.map_language_tool_category(&m.rule.category.id);
+            let suggestions: Vec<String> = m.replacements.into_iter().map(|r| r.value).collect();
+
+            // LanguageTool reports `offset`/`length` as character offsets,
+            // not byte offsets, so convert through the char->byte table
+            // before handing this to callers that slice `text` by byte
+            // index (same conversion as the Harper path below).
+            let start_byte = char_byte_table[m.offset.min(char_byte_table.len() - 1)];
+            let end_byte = char_byte_table[(m.offset + m.length).min(char_byte_table.len() - 1)];
+            let length = end_byte.saturating_sub(start_byte);
+
+            GrammarError {
+                message: m.message,
+                rule_id: m.rule.id,
+                category: m.rule.category.id.clone(),
+                offset: start_byte,
+                length,
+                context: Self::extract_context(text, &char_byte_table, start_byte, length),
+                suggestions,
+                severity: "warning".to_string(),
+                confidence,
+                error_type,
+            }
+        }).collect();
+
+        log::info!("LanguageTool check completed for {} characters", text.len());
+        Ok(errors)
+    }
+
+    fn map_language_tool_category(&self, category_id: &str) -> (ErrorType, f32) {
+        match category_id {
+            "TYPOS" => (ErrorType::Spelling, 0.9),
+            "GRAMMAR" => (ErrorType::Grammar, 0.85),
+            "PUNCTUATION" => (ErrorType::Punctuation, 0.8),
+            "STYLE" | "REDUNDANCY" => (ErrorType::Style, 0.6),
+            "CASING" => (ErrorType::Grammar, 0.8),
+            "CONFUSED_WORDS" => (ErrorType::Clarity, 0.7),
+            _ => (ErrorType::Other, 0.5),
+        }
+    }
+
     fn check_with_harper(&self, text: &str) -> Result<Vec<GrammarError>> {
-        use harper_core::{Document, linting::{LintGroup, Linter}, spell::FstDictionary, Dialect};
-        use std::sync::Arc;
+        use harper_core::{Document, linting::{LintGroup, Linter}, Dialect};
 
         // Create a new document from the text with proper Harper configuration
         let document = Document::new_plain_english_curated(text);
 
-        // Create a dictionary for Harper
-        let dictionary = Arc::new(FstDictionary::curated());
+        // Curated FST augmented with the user's personal vocabulary, if any.
+        let dictionary = self.harper_dictionary();
 
         // Create a comprehensive lint group with all Harper's built-in linters
         // Use American English dialect as default
@@ -489,13 +1069,18 @@ impl GrammarService {
         // Use Harper's built-in linting functionality
         let harper_lints = lint_group.lint(&document);
 
+        // Build the char->byte table once for the whole document instead of
+        // walking it with `char_indices().nth(n)` for every lint, which made
+        // span conversion O(n * lint count) on large OCR pages.
+        let char_byte_table = Self::build_char_byte_table(text);
+
         let mut errors = Vec::new();
 
         // Convert Harper's Lint objects to our GrammarError format
         for lint in harper_lints {
             let span = lint.span;
-            let start_byte = self.calculate_char_offset_to_byte(text, span.start);
-            let end_byte = self.calculate_char_offset_to_byte(text, span.end);
+            let start_byte = char_byte_table[span.start.min(char_byte_table.len() - 1)];
+            let end_byte = char_byte_table[span.end.min(char_byte_table.len() - 1)];
             let length = end_byte.saturating_sub(start_byte);
 
             // Convert Harper's suggestions to our format
@@ -524,7 +1109,7 @@ impl GrammarService {
                 category: self.get_harper_category(&lint.lint_kind),
                 offset: start_byte,
                 length,
-                context: self.extract_context(text, start_byte, length),
+                context: Self::extract_context(text, &char_byte_table, start_byte, length),
                 suggestions,
                 severity,
                 confidence,
@@ -584,26 +1169,44 @@ impl GrammarService {
         }
     }
 
-    fn calculate_char_offset_to_byte(&self, text: &str, char_index: usize) -> usize {
-        // Convert character index to byte offset in the original text
-        // This is a simplified approach that works for most cases
-        text.char_indices()
-            .nth(char_index)
-            .map(|(byte_index, _)| byte_index)
-            .unwrap_or(text.len())
+    /// Byte offset of the start of each character in `text`, plus a final
+    /// sentinel entry equal to `text.len()`. Lets callers convert a char
+    /// index to a byte offset in O(1) (by indexing) instead of re-walking
+    /// the string with `char_indices().nth(n)` for every lookup.
+    fn build_char_byte_table(text: &str) -> Vec<usize> {
+        let mut table: Vec<usize> = text.char_indices().map(|(byte_index, _)| byte_index).collect();
+        table.push(text.len());
+        table
     }
 
-
-
-    fn extract_context(&self, text: &str, offset: usize, length: usize) -> String {
-        let context_size = 50;
-        let start = offset.saturating_sub(context_size);
-        let end = (offset + length + context_size).min(text.len());
-
-        text.chars()
-            .skip(start)
-            .take(end - start)
-            .collect()
+    /// Extracts up to 50 characters of context on either side of
+    /// `[offset, offset + length)` (byte offsets) in `text`, using
+    /// `char_byte_table` (see `build_char_byte_table`) to find the
+    /// surrounding char boundaries via binary search instead of re-skipping
+    /// the string from the start for every error.
+    fn extract_context(text: &str, char_byte_table: &[usize], offset: usize, length: usize) -> String {
+        let context_chars = 50;
+
+        // `offset`/`length` come straight from grammar providers, including
+        // third-party WASM/external plugins (`check_with_plugins`), and are
+        // never validated against `text`. Clamp them to `text`'s length so a
+        // bogus or adversarial span can't overflow `offset + length` or
+        // otherwise drive the end index below the start one -- either of
+        // which would panic on the slice below and take down the whole
+        // grammar check, not just that plugin's result.
+        let offset = offset.min(text.len());
+        let end_offset = offset.saturating_add(length).min(text.len());
+
+        let start_char = char_byte_table.partition_point(|&b| b <= offset).saturating_sub(1);
+        let end_char = char_byte_table.partition_point(|&b| b < end_offset);
+
+        let ctx_start_char = start_char.saturating_sub(context_chars);
+        let ctx_end_char = (end_char + context_chars).min(char_byte_table.len() - 1).max(ctx_start_char);
+
+        let start_byte = char_byte_table[ctx_start_char];
+        let end_byte = char_byte_table[ctx_end_char];
+
+        text[start_byte..end_byte].to_string()
     }
 
 
@@ -629,18 +1232,84 @@ impl GrammarService {
         })
     }
 
+    /// Drops errors covered by an installed rule pack: spelling flags on a
+    /// pack's custom vocabulary, and any error whose matched text matches
+    /// one of the pack's ignore patterns.
+    fn apply_rule_pack_filters(&self, text: &str, errors: Vec<GrammarError>) -> Vec<GrammarError> {
+        if self.rule_pack_contents.custom_words.is_empty() && self.rule_pack_contents.ignore_patterns.is_empty() {
+            return errors;
+        }
+
+        errors
+            .into_iter()
+            .filter(|error| {
+                let Some(matched) = text.get(error.offset..error.offset + error.length) else {
+                    return true;
+                };
+
+                let is_known_word = matches!(error.error_type, ErrorType::Spelling)
+                    && self.rule_pack_contents.custom_words.iter().any(|w| w.eq_ignore_ascii_case(matched));
+
+                let is_ignored = self.rule_pack_contents.ignore_patterns.iter().any(|p| p == matched);
+
+                !is_known_word && !is_ignored
+            })
+            .collect()
+    }
+
+    /// Drops errors covered by `GrammarConfig.custom_words` /
+    /// `ignore_patterns` directly (as opposed to those contributed by an
+    /// installed rule pack, see `apply_rule_pack_filters`): spelling flags
+    /// on the user's own vocabulary, and any error whose matched text is
+    /// matched by one of the user's ignore regexes.
+    fn apply_custom_word_filters(&self, text: &str, errors: Vec<GrammarError>) -> Vec<GrammarError> {
+        if self.config.custom_words.is_empty() && self.config.ignore_patterns.is_empty() {
+            return errors;
+        }
+
+        let ignore_regexes: Vec<regex::Regex> = self
+            .config
+            .ignore_patterns
+            .iter()
+            .filter_map(|pattern| match regex::Regex::new(pattern) {
+                Ok(re) => Some(re),
+                Err(e) => {
+                    log::warn!("Invalid ignore_patterns regex '{}': {}", pattern, e);
+                    None
+                }
+            })
+            .collect();
+
+        errors
+            .into_iter()
+            .filter(|error| {
+                let Some(matched) = text.get(error.offset..error.offset + error.length) else {
+                    return true;
+                };
+
+                let is_known_word = matches!(error.error_type, ErrorType::Spelling)
+                    && self.config.custom_words.iter().any(|w| w.eq_ignore_ascii_case(matched));
+
+                let is_ignored = ignore_regexes.iter().any(|re| re.is_match(matched));
+
+                !is_known_word && !is_ignored
+            })
+            .collect()
+    }
+
     fn check_basic_patterns(&self, text: &str) -> Vec<GrammarError> {
         let mut errors = Vec::new();
 
         // Check for double spaces
         if let Some(pos) = text.find("  ") {
+            let char_byte_table = Self::build_char_byte_table(text);
             errors.push(GrammarError {
                 message: "Multiple consecutive spaces found".to_string(),
                 rule_id: "DOUBLE_SPACE".to_string(),
                 category: "Whitespace".to_string(),
                 offset: pos,
                 length: 2,
-                context: self.extract_context(text, pos, 2),
+                context: Self::extract_context(text, &char_byte_table, pos, 2),
                 suggestions: vec![" ".to_string()],
                 severity: "info".to_string(),
                 confidence: 0.9,
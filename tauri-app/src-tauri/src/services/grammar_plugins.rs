@@ -0,0 +1,243 @@
+use crate::error::{AppError, AppResult, ErrorCode};
+use crate::utils::path_utils;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use wasmtime::{Config, Engine, Instance, Memory, Module, Store};
+
+/// How much `wasmtime` fuel a single `check()` call may burn before being
+/// killed. The wall-clock ceiling that bounds the call as a whole (in case a
+/// plugin stalls somewhere fuel doesn't reach, like instantiation) is
+/// enforced by the caller, which runs `check()` on a blocking thread under
+/// `tokio::time::timeout` -- see `GrammarService::check_with_plugins`.
+const PLUGIN_FUEL_LIMIT: u64 = 50_000_000;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GrammarPluginInfo {
+    pub name: String,
+    pub wasm_path: String,
+}
+
+/// One error surfaced by a plugin's `check` export, marshalled out of its
+/// linear memory as JSON (see `WasmGrammarProvider::check`).
+#[derive(Debug, Clone, Deserialize)]
+pub struct RawPluginError {
+    pub offset: usize,
+    pub length: usize,
+    pub error_type: String,
+    pub message: String,
+    #[serde(default)]
+    pub suggestions: Vec<String>,
+}
+
+/// Loads a single `.wasm` grammar plugin and runs its `check` export inside
+/// a fuel- and time-bounded `wasmtime` store.
+///
+/// Host/plugin contract: the module exports `memory`, an `alloc(len) -> ptr`
+/// function for the host to write the input into, and
+/// `check(text_ptr, text_len, lang_ptr, lang_len) -> packed_result` where the
+/// high 32 bits of the returned u64 are the output pointer and the low 32
+/// bits are its length. The output bytes are a JSON-encoded `Vec<RawPluginError>`.
+pub struct WasmGrammarProvider {
+    name: String,
+    engine: Engine,
+    module: Module,
+}
+
+impl WasmGrammarProvider {
+    pub fn load(wasm_path: &Path) -> AppResult<Self> {
+        let mut config = Config::new();
+        config.consume_fuel(true);
+
+        let engine = Engine::new(&config).map_err(|e| {
+            AppError::with_details(ErrorCode::InternalError, "Failed to create WASM engine", e.to_string())
+        })?;
+
+        let module = Module::from_file(&engine, wasm_path).map_err(|e| {
+            AppError::with_details(
+                ErrorCode::InternalError,
+                "Failed to load grammar plugin module",
+                e.to_string(),
+            )
+        })?;
+
+        let name = wasm_path
+            .file_stem()
+            .map(|s| s.to_string_lossy().to_string())
+            .unwrap_or_else(|| "unknown-plugin".to_string());
+
+        Ok(Self { name, engine, module })
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Runs the plugin's `check` export against `text`, returning the raw
+    /// errors it reports. Any WASM trap, missing export, or fuel/time
+    /// exhaustion is treated as a failed plugin rather than a hard error —
+    /// one bad plugin shouldn't break grammar checking for everyone else.
+    pub fn check(&self, text: &str, language: &str) -> AppResult<Vec<RawPluginError>> {
+        let mut store = Store::new(&self.engine, ());
+        store.set_fuel(PLUGIN_FUEL_LIMIT).map_err(|e| {
+            AppError::with_details(ErrorCode::InternalError, "Failed to set plugin fuel budget", e.to_string())
+        })?;
+
+        let instance = Instance::new(&mut store, &self.module, &[]).map_err(|e| {
+            AppError::with_details(
+                ErrorCode::InternalError,
+                "Failed to instantiate grammar plugin",
+                e.to_string(),
+            )
+        })?;
+
+        let memory = instance.get_memory(&mut store, "memory").ok_or_else(|| {
+            AppError::new(ErrorCode::InternalError, "Grammar plugin does not export `memory`")
+        })?;
+        let alloc = instance
+            .get_typed_func::<u32, u32>(&mut store, "alloc")
+            .map_err(|e| {
+                AppError::with_details(ErrorCode::InternalError, "Grammar plugin does not export `alloc`", e.to_string())
+            })?;
+        let check = instance
+            .get_typed_func::<(u32, u32, u32, u32), u64>(&mut store, "check")
+            .map_err(|e| {
+                AppError::with_details(ErrorCode::InternalError, "Grammar plugin does not export `check`", e.to_string())
+            })?;
+
+        let text_ptr = Self::write_bytes(&mut store, &memory, &alloc, text.as_bytes())?;
+        let lang_ptr = Self::write_bytes(&mut store, &memory, &alloc, language.as_bytes())?;
+
+        let packed = check
+            .call(&mut store, (text_ptr, text.len() as u32, lang_ptr, language.len() as u32))
+            .map_err(|e| {
+                AppError::with_details(ErrorCode::InternalError, "Grammar plugin check() trapped", e.to_string())
+            })?;
+
+        let out_ptr = (packed >> 32) as u32 as usize;
+        let out_len = (packed & 0xFFFF_FFFF) as u32 as usize;
+
+        let data = memory.data(&store);
+        let bytes = data.get(out_ptr..out_ptr + out_len).ok_or_else(|| {
+            AppError::new(ErrorCode::InternalError, "Grammar plugin returned an out-of-bounds buffer")
+        })?;
+
+        serde_json::from_slice(bytes).map_err(|e| {
+            AppError::with_details(
+                ErrorCode::InternalError,
+                "Failed to parse grammar plugin output",
+                e.to_string(),
+            )
+        })
+    }
+
+    fn write_bytes(
+        store: &mut Store<()>,
+        memory: &Memory,
+        alloc: &wasmtime::TypedFunc<u32, u32>,
+        bytes: &[u8],
+    ) -> AppResult<u32> {
+        let ptr = alloc.call(&mut *store, bytes.len() as u32).map_err(|e| {
+            AppError::with_details(ErrorCode::InternalError, "Grammar plugin alloc() trapped", e.to_string())
+        })?;
+
+        memory.write(&mut *store, ptr as usize, bytes).map_err(|e| {
+            AppError::with_details(ErrorCode::InternalError, "Failed to write into plugin memory", e.to_string())
+        })?;
+
+        Ok(ptr)
+    }
+}
+
+/// Installs, lists and removes `.wasm` grammar plugins under a runtime
+/// directory, mirroring `RulePackManager`'s manifest-based bookkeeping.
+pub struct GrammarPluginManager {
+    plugins_dir: PathBuf,
+}
+
+impl GrammarPluginManager {
+    pub fn new(app_data_dir: &Path) -> AppResult<Self> {
+        let plugins_dir = app_data_dir.join("grammar_plugins");
+        path_utils::ensure_directory_exists(&plugins_dir.to_string_lossy())?;
+        Ok(Self { plugins_dir })
+    }
+
+    fn manifest_path(&self) -> PathBuf {
+        self.plugins_dir.join("manifest.json")
+    }
+
+    fn load_manifest(&self) -> Vec<GrammarPluginInfo> {
+        fs::read_to_string(self.manifest_path())
+            .ok()
+            .and_then(|s| serde_json::from_str(&s).ok())
+            .unwrap_or_default()
+    }
+
+    fn save_manifest(&self, plugins: &[GrammarPluginInfo]) -> AppResult<()> {
+        let json = serde_json::to_string_pretty(plugins).map_err(|e| {
+            AppError::with_details(
+                ErrorCode::InternalError,
+                "Failed to serialize grammar plugin manifest",
+                e.to_string(),
+            )
+        })?;
+        fs::write(self.manifest_path(), json)?;
+        Ok(())
+    }
+
+    /// Copies the `.wasm` module at `source_path` into the plugins directory
+    /// and registers it, failing if it doesn't load as a valid module.
+    pub fn install_plugin(&self, source_path: &str) -> AppResult<GrammarPluginInfo> {
+        let source = Path::new(source_path);
+        let file_name = source
+            .file_name()
+            .ok_or_else(|| AppError::new(ErrorCode::InvalidInput, "Plugin path has no file name"))?;
+
+        let dest_path = self.plugins_dir.join(file_name);
+        fs::copy(source, &dest_path)?;
+
+        let provider = WasmGrammarProvider::load(&dest_path)?;
+        let info = GrammarPluginInfo {
+            name: provider.name().to_string(),
+            wasm_path: dest_path.to_string_lossy().to_string(),
+        };
+
+        let mut plugins = self.load_manifest();
+        plugins.retain(|p| p.name != info.name);
+        plugins.push(info.clone());
+        self.save_manifest(&plugins)?;
+
+        Ok(info)
+    }
+
+    pub fn list_plugins(&self) -> Vec<GrammarPluginInfo> {
+        self.load_manifest()
+    }
+
+    pub fn remove_plugin(&self, name: &str) -> AppResult<()> {
+        let mut plugins = self.load_manifest();
+
+        if let Some(pos) = plugins.iter().position(|p| p.name == name) {
+            let plugin = plugins.remove(pos);
+            let _ = fs::remove_file(&plugin.wasm_path);
+            self.save_manifest(&plugins)?;
+        }
+
+        Ok(())
+    }
+
+    /// Loads every installed plugin as a `WasmGrammarProvider`, skipping (and
+    /// logging) any that fail to load rather than failing the whole batch.
+    pub fn load_providers(&self) -> Vec<WasmGrammarProvider> {
+        self.load_manifest()
+            .into_iter()
+            .filter_map(|info| match WasmGrammarProvider::load(Path::new(&info.wasm_path)) {
+                Ok(provider) => Some(provider),
+                Err(e) => {
+                    log::warn!("Failed to load grammar plugin '{}': {}", info.name, e);
+                    None
+                }
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,115 @@
+use crate::error::{AppError, AppResult, ErrorCode};
+use crate::services::file_handler::FileHandlerService;
+
+/// Upper bounds enforced before OCR or frame extraction starts on a file, so
+/// oversized input fails fast with a precise error instead of late inside the
+/// pipeline — or not at all, as an OOM from decoding a multi-hour 4K video.
+#[derive(Debug, Clone)]
+pub struct MediaLimits {
+    pub max_file_size_bytes: u64,
+    pub max_image_megapixels: f64,
+    pub max_video_duration_secs: f64,
+    pub max_frames_to_extract: u32,
+}
+
+impl Default for MediaLimits {
+    fn default() -> Self {
+        Self {
+            max_file_size_bytes: 500 * 1024 * 1024,
+            max_image_megapixels: 100.0,
+            max_video_duration_secs: 2.0 * 60.0 * 60.0,
+            max_frames_to_extract: 5000,
+        }
+    }
+}
+
+impl MediaLimits {
+    fn check_file_size(&self, file_path: &str) -> AppResult<()> {
+        let size = std::fs::metadata(file_path)
+            .map_err(|e| {
+                AppError::with_details(
+                    ErrorCode::FileAccess,
+                    "Failed to read file metadata",
+                    e.to_string(),
+                )
+            })?
+            .len();
+
+        if size > self.max_file_size_bytes {
+            return Err(AppError::with_details(
+                ErrorCode::FileValidation,
+                "File exceeds the maximum allowed size",
+                format!(
+                    "File is {} bytes, limit is {} bytes",
+                    size, self.max_file_size_bytes
+                ),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Validates `image_path` against the size and megapixel limits before
+    /// `process_image_ocr` loads it for preprocessing.
+    pub fn check_image(&self, image_path: &str) -> AppResult<()> {
+        self.check_file_size(image_path)?;
+
+        let metadata = FileHandlerService::get_media_metadata(image_path)?;
+        if let (Some(width), Some(height)) = (metadata.width, metadata.height) {
+            let megapixels = (width as f64 * height as f64) / 1_000_000.0;
+            if megapixels > self.max_image_megapixels {
+                return Err(AppError::with_details(
+                    ErrorCode::MediaDimensions,
+                    "Image exceeds the maximum allowed resolution",
+                    format!(
+                        "Image is {}x{} ({:.1} MP), limit is {:.1} MP",
+                        width, height, megapixels, self.max_image_megapixels
+                    ),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Validates `video_path` against the size, duration, and estimated
+    /// frame-count limits before `process_video_ocr`/`extract_frames_from_video`
+    /// run. `frame_interval` is the sampling interval the caller intends to
+    /// use, needed to estimate how many frames would actually be extracted.
+    pub fn check_video(&self, video_path: &str, frame_interval: Option<u32>) -> AppResult<()> {
+        self.check_file_size(video_path)?;
+
+        let metadata = FileHandlerService::get_media_metadata(video_path)?;
+
+        if let Some(duration) = metadata.duration_seconds {
+            if duration > self.max_video_duration_secs {
+                return Err(AppError::with_details(
+                    ErrorCode::MediaTooLong,
+                    "Video exceeds the maximum allowed duration",
+                    format!(
+                        "Video is {:.1}s, limit is {:.1}s",
+                        duration, self.max_video_duration_secs
+                    ),
+                ));
+            }
+        }
+
+        if let (Some(duration), Some(frame_rate)) = (metadata.duration_seconds, metadata.frame_rate)
+        {
+            let interval = frame_interval.unwrap_or(30).max(1) as f64;
+            let estimated_frames = (duration * frame_rate / interval).ceil() as u32;
+            if estimated_frames > self.max_frames_to_extract {
+                return Err(AppError::with_details(
+                    ErrorCode::TooManyFrames,
+                    "Video would yield more frames than the maximum allowed",
+                    format!(
+                        "Estimated {} frames, limit is {}",
+                        estimated_frames, self.max_frames_to_extract
+                    ),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,171 @@
+use crate::error::{AppResult, AppError, ErrorCode};
+use crate::services::file_handler::{FileHandlerService, FileType};
+use image::GenericImageView;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+/// Format-specific details beyond what `FileInfo` tracks, so callers can make
+/// preprocessing decisions without decoding the file twice — e.g. auto-rotate
+/// using EXIF orientation, skip upscaling an already high-DPI scan, or derive
+/// a sensible `frame_interval` from a video's frame rate.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MediaMetadata {
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+    /// Raw EXIF orientation tag value (1-8); `None` when absent or not an image.
+    pub orientation: Option<u32>,
+    /// Horizontal DPI from the EXIF `XResolution` tag.
+    pub dpi: Option<u32>,
+    pub duration_seconds: Option<f64>,
+    pub frame_rate: Option<f64>,
+    pub codec: Option<String>,
+    pub page_count: Option<u32>,
+}
+
+impl FileHandlerService {
+    /// Extracts format-specific metadata, dispatched on `determine_file_type`:
+    /// dimensions/orientation/DPI for images, duration/dimensions/frame
+    /// rate/codec for video (via an ffmpeg probe), and page count for PDFs.
+    pub fn get_media_metadata(file_path: &str) -> AppResult<MediaMetadata> {
+        let extension = Path::new(file_path)
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .unwrap_or("")
+            .to_lowercase();
+
+        match Self::determine_file_type(&extension) {
+            FileType::Image => Ok(extract_image_metadata(file_path)),
+            FileType::Video => extract_video_metadata(file_path),
+            FileType::Pdf => extract_pdf_metadata(file_path),
+            FileType::Document | FileType::Unknown => Ok(MediaMetadata::default()),
+        }
+    }
+}
+
+fn extract_image_metadata(file_path: &str) -> MediaMetadata {
+    let mut metadata = MediaMetadata::default();
+
+    if let Ok(image) = image::open(file_path) {
+        let (width, height) = image.dimensions();
+        metadata.width = Some(width);
+        metadata.height = Some(height);
+    }
+
+    if let Ok(file) = fs::File::open(file_path) {
+        let mut reader = std::io::BufReader::new(file);
+        if let Ok(exif) = exif::Reader::new().read_from_container(&mut reader) {
+            if let Some(field) = exif.get_field(exif::Tag::Orientation, exif::In::PRIMARY) {
+                metadata.orientation = field.value.get_uint(0);
+            }
+
+            if let Some(field) = exif.get_field(exif::Tag::XResolution, exif::In::PRIMARY) {
+                if let exif::Value::Rational(ref values) = field.value {
+                    if let Some(resolution) = values.first() {
+                        metadata.dpi = Some(resolution.to_f64().round() as u32);
+                    }
+                }
+            }
+        }
+    }
+
+    metadata
+}
+
+fn extract_video_metadata(file_path: &str) -> AppResult<MediaMetadata> {
+    ffmpeg_next::init().map_err(|e| {
+        AppError::with_details(
+            ErrorCode::InternalError,
+            "Failed to initialize ffmpeg",
+            e.to_string(),
+        )
+    })?;
+
+    let input = ffmpeg_next::format::input(&file_path).map_err(|e| {
+        AppError::with_details(
+            ErrorCode::InternalError,
+            "Failed to open video file",
+            e.to_string(),
+        )
+    })?;
+
+    let stream = input
+        .streams()
+        .best(ffmpeg_next::media::Type::Video)
+        .ok_or_else(|| AppError::new(ErrorCode::InternalError, "Video has no video stream"))?;
+
+    let frame_rate = {
+        let rate = stream.rate();
+        if rate.denominator() != 0 {
+            Some(rate.numerator() as f64 / rate.denominator() as f64)
+        } else {
+            None
+        }
+    };
+
+    let context = ffmpeg_next::codec::context::Context::from_parameters(stream.parameters())
+        .map_err(|e| {
+            AppError::with_details(
+                ErrorCode::InternalError,
+                "Failed to read codec parameters",
+                e.to_string(),
+            )
+        })?;
+    let decoder = context.decoder().video().map_err(|e| {
+        AppError::with_details(
+            ErrorCode::InternalError,
+            "Failed to open video decoder",
+            e.to_string(),
+        )
+    })?;
+
+    let duration_seconds = if input.duration() > 0 {
+        Some(input.duration() as f64 / f64::from(ffmpeg_next::ffi::AV_TIME_BASE))
+    } else {
+        None
+    };
+
+    Ok(MediaMetadata {
+        width: Some(decoder.width()),
+        height: Some(decoder.height()),
+        duration_seconds,
+        frame_rate,
+        codec: Some(format!("{:?}", decoder.id())),
+        ..Default::default()
+    })
+}
+
+fn extract_pdf_metadata(file_path: &str) -> AppResult<MediaMetadata> {
+    let bytes = fs::read(file_path).map_err(|e| {
+        AppError::with_details(
+            ErrorCode::FileAccess,
+            "Failed to read PDF file",
+            e.to_string(),
+        )
+    })?;
+
+    Ok(MediaMetadata {
+        page_count: Some(count_pdf_pages(&bytes)),
+        ..Default::default()
+    })
+}
+
+/// Counts `/Type /Page` object dictionaries, skipping `/Type /Pages` (the
+/// tree-node objects the substring would otherwise also match). Naive but
+/// cheap: exact enough for typical, non-maliciously-crafted PDFs without
+/// pulling in a full PDF parser just for a page count.
+fn count_pdf_pages(bytes: &[u8]) -> u32 {
+    let text = String::from_utf8_lossy(bytes);
+    let mut count = 0;
+
+    for pattern in ["/Type/Page", "/Type /Page"] {
+        for (index, _) in text.match_indices(pattern) {
+            let after = index + pattern.len();
+            if text.as_bytes().get(after) != Some(&b's') {
+                count += 1;
+            }
+        }
+    }
+
+    count
+}
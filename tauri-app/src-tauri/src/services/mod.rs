@@ -0,0 +1,29 @@
+pub mod ocr;
+pub mod grammar;
+pub mod file_handler;
+pub mod csv_exporter;
+pub mod rule_packs;
+pub mod translation;
+pub mod grammar_plugins;
+pub mod extraction_cache;
+pub mod video_frames;
+pub mod media_metadata;
+pub mod media_limits;
+pub mod exporters;
+pub mod result_cache;
+pub mod external_plugins;
+
+pub use ocr::*;
+pub use grammar::*;
+pub use file_handler::*;
+pub use csv_exporter::*;
+pub use rule_packs::*;
+pub use translation::*;
+pub use grammar_plugins::*;
+pub use extraction_cache::*;
+pub use video_frames::*;
+pub use media_metadata::*;
+pub use media_limits::*;
+pub use exporters::*;
+pub use result_cache::*;
+pub use external_plugins::*;
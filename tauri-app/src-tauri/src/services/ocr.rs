@@ -36,6 +36,33 @@ pub struct PreprocessingOptions {
     pub denoise: bool,
     pub threshold_method: String,
     pub apply_morphology: bool,
+    pub frame_selection_strategy: FrameSelectionStrategy,
+}
+
+/// Controls which decoded video frames are kept, based on a 64-bit
+/// average-hash comparison against the last kept frame. It is applied by
+/// `video_frames::extract_frames_native` and sent to the OCR backend by
+/// `extract_text_from_video`; `FileHandlerService::extract_frames_from_video`
+/// takes no strategy: its native backend uses the default `FixedInterval`
+/// and its Python backend sends no strategy to the service.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FrameSelectionStrategy {
+    /// Keep every `frame_interval`-th frame unconditionally — the original,
+    /// purely time-based sampling.
+    FixedInterval,
+    /// Keep a frame only when its Hamming distance to the last kept frame
+    /// is large, signaling a hard cut rather than gradual motion.
+    SceneChange,
+    /// Keep a frame whenever it's even moderately different from the last
+    /// kept one, collapsing near-duplicate frames like static talking-head
+    /// footage down to a handful of visually distinct frames.
+    Deduplicate,
+}
+
+impl Default for FrameSelectionStrategy {
+    fn default() -> Self {
+        FrameSelectionStrategy::FixedInterval
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -62,6 +89,7 @@ impl Default for PreprocessingOptions {
             denoise: true,
             threshold_method: "adaptive_gaussian".to_string(),
             apply_morphology: true,
+            frame_selection_strategy: FrameSelectionStrategy::default(),
         }
     }
 }
@@ -145,6 +173,8 @@ impl OCRService {
             ));
         }
 
+        crate::services::MediaLimits::default().check_image(image_path)?;
+
         // Create multipart form with file path (more efficient than uploading entire file)
         let opts = options.unwrap_or_default();
         let mut form = reqwest::multipart::Form::new()
@@ -210,12 +240,20 @@ impl OCRService {
             ));
         }
 
+        crate::services::MediaLimits::default().check_video(video_path, Some(5))?;
+
         // Create multipart form with file path (more efficient than uploading entire file)
         let opts = options.unwrap_or_default();
+        let frame_selection_strategy = match opts.frame_selection_strategy {
+            FrameSelectionStrategy::FixedInterval => "fixed_interval",
+            FrameSelectionStrategy::SceneChange => "scene_change",
+            FrameSelectionStrategy::Deduplicate => "deduplicate",
+        };
         let mut form = reqwest::multipart::Form::new()
             .text("file_path", video_path.to_string())
             .text("frame_interval", "5")
             .text("similarity_threshold", "0.98")
+            .text("frame_selection_strategy", frame_selection_strategy)
             .text("min_confidence", "0.6")
             .text("max_frames", "1000")
             .text("enhance_contrast", opts.enhance_contrast.to_string())
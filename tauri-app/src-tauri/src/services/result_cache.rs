@@ -0,0 +1,122 @@
+use crate::error::{AppError, AppResult, ErrorCode};
+use crate::services::csv_exporter::ExportRecord;
+use crate::services::extraction_cache::ExtractionCache;
+use crate::utils::path_utils;
+use dashmap::DashMap;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// A cached OCR result for one `(file, engine)` pair, content-addressed by
+/// `(path, size, modified_date, ocr_engine)` rather than just `path` --
+/// unlike [`ExtractionCache`], re-running the same file through a different
+/// engine is a cache miss, not a hit.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResultCacheEntry {
+    pub path: String,
+    pub size: u64,
+    pub modified_date: u64,
+    pub ocr_engine: String,
+    pub record: ExportRecord,
+}
+
+/// Persists completed `ExportRecord` results across the whole app lifetime --
+/// loaded once at startup and written back on `tauri://close-requested` --
+/// unlike `ExtractionCache`, which is loaded and saved around each batch run.
+/// Entries are kept per OCR engine, so a file re-run through a different OCR
+/// engine still gets its own entry.
+pub struct ResultCache {
+    cache_file: PathBuf,
+}
+
+impl ResultCache {
+    pub fn new(app_data_dir: &Path) -> AppResult<Self> {
+        let cache_dir = app_data_dir.join("cache");
+        path_utils::ensure_directory_exists(&cache_dir.to_string_lossy())?;
+        Ok(Self {
+            cache_file: cache_dir.join("result_cache.json"),
+        })
+    }
+
+    /// Loads the persisted cache into an in-memory `DashMap` so concurrent
+    /// lookups don't need an external lock.
+    pub fn load(&self) -> DashMap<String, ResultCacheEntry> {
+        fs::read_to_string(&self.cache_file)
+            .ok()
+            .and_then(|s| serde_json::from_str::<Vec<ResultCacheEntry>>(&s).ok())
+            .map(|entries| {
+                entries
+                    .into_iter()
+                    .map(|e| (Self::key(&e.path, &e.ocr_engine), e))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self, entries: &DashMap<String, ResultCacheEntry>) -> AppResult<()> {
+        let snapshot: Vec<ResultCacheEntry> = entries.iter().map(|e| e.value().clone()).collect();
+        let json = serde_json::to_string_pretty(&snapshot).map_err(|e| {
+            AppError::with_details(
+                ErrorCode::InternalError,
+                "Failed to serialize result cache",
+                e.to_string(),
+            )
+        })?;
+        fs::write(&self.cache_file, json)?;
+        Ok(())
+    }
+
+    /// Combines path + OCR engine into the map key. `ExtractionCache::fingerprint`
+    /// supplies the size/modified_date half of the fingerprint used to
+    /// validate an entry on lookup.
+    fn key(path: &str, ocr_engine: &str) -> String {
+        format!("{}::{}", path, ocr_engine)
+    }
+
+    /// Looks up `path`/`ocr_engine` in `entries`, returning the cached record
+    /// only if the file's current `(size, modified_date)` still matches what
+    /// was cached -- any mismatch, or a file that no longer stats, is a miss.
+    pub fn get(
+        entries: &DashMap<String, ResultCacheEntry>,
+        path: &str,
+        ocr_engine: &str,
+    ) -> Option<ExportRecord> {
+        let (size, modified_date) = ExtractionCache::fingerprint(path).ok()?;
+        let entry = entries.get(&Self::key(path, ocr_engine))?;
+        if entry.size == size && entry.modified_date == modified_date {
+            Some(entry.record.clone())
+        } else {
+            None
+        }
+    }
+
+    pub fn insert(
+        entries: &DashMap<String, ResultCacheEntry>,
+        path: &str,
+        ocr_engine: &str,
+        record: ExportRecord,
+    ) {
+        if let Ok((size, modified_date)) = ExtractionCache::fingerprint(path) {
+            entries.insert(
+                Self::key(path, ocr_engine),
+                ResultCacheEntry {
+                    path: path.to_string(),
+                    size,
+                    modified_date,
+                    ocr_engine: ocr_engine.to_string(),
+                    record,
+                },
+            );
+        }
+    }
+}
+
+/// Progress snapshot emitted while scanning a file list against the result
+/// cache, so the UI can show how much OCR work is about to be skipped before
+/// any of it actually runs.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResultCacheProgress {
+    pub files_checked: usize,
+    pub files_to_check: usize,
+    pub cache_hits: usize,
+}
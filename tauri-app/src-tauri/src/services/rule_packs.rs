@@ -0,0 +1,260 @@
+use crate::error::{AppError, AppResult, ErrorCode};
+use crate::utils::path_utils;
+use serde::{Deserialize, Serialize};
+use std::collections::hash_map::DefaultHasher;
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Where a rule pack's files come from.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum RulePackSource {
+    Local { path: String },
+    Git { remote: String, rev: String, subpath: Option<String> },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RulePackInfo {
+    pub name: String,
+    pub source: RulePackSource,
+    pub revision: String,
+    pub installed_path: String,
+}
+
+/// The custom vocabulary/ignore rules a pack contributes to the grammar
+/// engine, merged across every pack a `GrammarConfig` names.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct RulePackContents {
+    pub custom_words: Vec<String>,
+    pub ignore_patterns: Vec<String>,
+}
+
+/// Installs, lists and removes dictionary/rule packs under a runtime
+/// directory, caching installs by revision so re-installing the same
+/// pinned revision is a no-op.
+pub struct RulePackManager {
+    packs_dir: PathBuf,
+}
+
+impl RulePackManager {
+    pub fn new(app_data_dir: &Path) -> AppResult<Self> {
+        let packs_dir = app_data_dir.join("rule_packs");
+        path_utils::ensure_directory_exists(&packs_dir.to_string_lossy())?;
+        Ok(Self { packs_dir })
+    }
+
+    fn manifest_path(&self) -> PathBuf {
+        self.packs_dir.join("manifest.json")
+    }
+
+    fn load_manifest(&self) -> Vec<RulePackInfo> {
+        fs::read_to_string(self.manifest_path())
+            .ok()
+            .and_then(|s| serde_json::from_str(&s).ok())
+            .unwrap_or_default()
+    }
+
+    fn save_manifest(&self, packs: &[RulePackInfo]) -> AppResult<()> {
+        let json = serde_json::to_string_pretty(packs).map_err(|e| {
+            AppError::with_details(
+                ErrorCode::InternalError,
+                "Failed to serialize rule pack manifest",
+                e.to_string(),
+            )
+        })?;
+        fs::write(self.manifest_path(), json)?;
+        Ok(())
+    }
+
+    pub fn install_rule_pack(&self, name: &str, source: RulePackSource) -> AppResult<RulePackInfo> {
+        let mut packs = self.load_manifest();
+
+        let revision = match &source {
+            // A constant revision would make every re-install of an edited
+            // local source dir look identical to the last one, so the
+            // no-op check below would never re-copy it. Hash the source
+            // dir's contents instead, so editing `words.txt` and
+            // reinstalling actually picks up the change.
+            RulePackSource::Local { path } => Self::hash_dir_contents(Path::new(path))?,
+            RulePackSource::Git { rev, .. } => rev.clone(),
+        };
+
+        // Already installed at this exact revision: treat as a no-op.
+        if let Some(existing) = packs.iter().find(|p| p.name == name && p.revision == revision) {
+            return Ok(existing.clone());
+        }
+
+        let pack_dir = self.packs_dir.join(format!("{}-{}", name, revision));
+
+        match &source {
+            RulePackSource::Local { path } => Self::copy_dir_recursive(Path::new(path), &pack_dir)?,
+            RulePackSource::Git { remote, rev, subpath } => {
+                Self::clone_and_checkout(remote, rev, subpath.as_deref(), &pack_dir)?
+            }
+        }
+
+        let info = RulePackInfo {
+            name: name.to_string(),
+            source,
+            revision,
+            installed_path: pack_dir.to_string_lossy().to_string(),
+        };
+
+        packs.retain(|p| p.name != name);
+        packs.push(info.clone());
+        self.save_manifest(&packs)?;
+
+        Ok(info)
+    }
+
+    pub fn list_rule_packs(&self) -> Vec<RulePackInfo> {
+        self.load_manifest()
+    }
+
+    pub fn remove_rule_pack(&self, name: &str) -> AppResult<()> {
+        let mut packs = self.load_manifest();
+
+        if let Some(pos) = packs.iter().position(|p| p.name == name) {
+            let pack = packs.remove(pos);
+            let _ = fs::remove_dir_all(&pack.installed_path);
+            self.save_manifest(&packs)?;
+        }
+
+        Ok(())
+    }
+
+    /// Loads and merges the `words.txt` / `ignore.txt` contents of every
+    /// installed pack in `names`; packs that are not installed are skipped.
+    pub fn load_contents(&self, names: &[String]) -> RulePackContents {
+        let packs = self.load_manifest();
+        let mut merged = RulePackContents::default();
+
+        for name in names {
+            let Some(pack) = packs.iter().find(|p| &p.name == name) else {
+                continue;
+            };
+
+            let pack_path = Path::new(&pack.installed_path);
+
+            if let Ok(contents) = fs::read_to_string(pack_path.join("words.txt")) {
+                merged.custom_words.extend(
+                    contents.lines().map(|l| l.trim().to_string()).filter(|l| !l.is_empty()),
+                );
+            }
+
+            if let Ok(contents) = fs::read_to_string(pack_path.join("ignore.txt")) {
+                merged.ignore_patterns.extend(
+                    contents.lines().map(|l| l.trim().to_string()).filter(|l| !l.is_empty()),
+                );
+            }
+        }
+
+        merged
+    }
+
+    /// Hashes a local pack source directory's relative file paths and
+    /// contents so `install_rule_pack` can tell an edited source dir apart
+    /// from the last install, instead of pinning `Local` packs to a fixed
+    /// revision string that never changes.
+    fn hash_dir_contents(src: &Path) -> AppResult<String> {
+        let mut files = Vec::new();
+        Self::collect_files(src, src, &mut files)?;
+        files.sort();
+
+        let mut hasher = DefaultHasher::new();
+        for relative_path in files {
+            relative_path.hash(&mut hasher);
+            let contents = fs::read(src.join(&relative_path))?;
+            contents.hash(&mut hasher);
+        }
+
+        Ok(format!("{:x}", hasher.finish()))
+    }
+
+    fn collect_files(root: &Path, dir: &Path, out: &mut Vec<String>) -> AppResult<()> {
+        for entry in fs::read_dir(dir)? {
+            let entry = entry?;
+            let path = entry.path();
+
+            if entry.file_type()?.is_dir() {
+                Self::collect_files(root, &path, out)?;
+            } else {
+                let relative = path.strip_prefix(root).unwrap_or(&path).to_string_lossy().to_string();
+                out.push(relative);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn copy_dir_recursive(src: &Path, dst: &Path) -> AppResult<()> {
+        fs::create_dir_all(dst)?;
+
+        for entry in fs::read_dir(src)? {
+            let entry = entry?;
+            let dest_path = dst.join(entry.file_name());
+
+            if entry.file_type()?.is_dir() {
+                Self::copy_dir_recursive(&entry.path(), &dest_path)?;
+            } else {
+                fs::copy(entry.path(), &dest_path)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn clone_and_checkout(remote: &str, rev: &str, subpath: Option<&str>, dest: &Path) -> AppResult<()> {
+        // A `remote`/`rev` starting with `-` would be parsed by git as an
+        // option rather than a URL/revision (e.g. `--upload-pack=sh -c ...`
+        // on `git clone`) -- a well-known argument-injection vector that
+        // reaches arbitrary command execution. Reject both outright instead
+        // of passing them through.
+        if remote.starts_with('-') || rev.starts_with('-') {
+            return Err(AppError::new(
+                ErrorCode::InvalidInput,
+                "Git rule pack remote/revision may not start with '-'",
+            ));
+        }
+
+        if dest.exists() {
+            fs::remove_dir_all(dest)?;
+        }
+
+        // `--` also stops `remote` from being parsed as an option here, as
+        // defense in depth alongside the check above.
+        let clone_output = Command::new("git")
+            .args(["clone", "--", remote, &dest.to_string_lossy()])
+            .output()
+            .map_err(|e| AppError::from_spawn_error("git", &e))?;
+
+        if !clone_output.status.success() {
+            return Err(AppError::from_process_output("git clone", &clone_output));
+        }
+
+        let checkout_output = Command::new("git")
+            .args(["checkout", rev])
+            .current_dir(dest)
+            .output()
+            .map_err(|e| AppError::from_spawn_error("git", &e))?;
+
+        if !checkout_output.status.success() {
+            return Err(AppError::from_process_output("git checkout", &checkout_output));
+        }
+
+        // If only a subdirectory of the clone is the actual pack, hoist it
+        // up to `dest` so `installed_path` always points at the pack root.
+        if let Some(subpath) = subpath {
+            let nested = dest.join(subpath);
+            if nested.exists() {
+                let tmp = dest.with_extension("tmp");
+                fs::rename(&nested, &tmp)?;
+                fs::remove_dir_all(dest)?;
+                fs::rename(&tmp, dest)?;
+            }
+        }
+
+        Ok(())
+    }
+}
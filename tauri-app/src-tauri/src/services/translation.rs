@@ -0,0 +1,191 @@
+use crate::error::{AppError, AppResult, ErrorCode};
+use async_trait::async_trait;
+use std::collections::HashMap;
+
+/// A backend capable of translating a single span of text into one target
+/// language. Implementations are swappable behind `TranslationService` so
+/// the HTTP default can later be replaced with an offline/on-device engine.
+#[async_trait]
+pub trait TranslationProvider: Send + Sync {
+    async fn translate(&self, text: &str, source_lang: &str, target_lang: &str) -> AppResult<String>;
+}
+
+/// Default provider that POSTs to a configurable translation endpoint,
+/// mirroring the OCR/document services' `BACKEND_URL`-style configuration.
+pub struct HttpTranslationProvider {
+    http_client: reqwest::Client,
+    service_url: String,
+}
+
+impl HttpTranslationProvider {
+    pub fn new() -> Self {
+        let service_url = std::env::var("TRANSLATION_SERVICE_URL")
+            .unwrap_or_else(|_| "http://127.0.0.1:8000".to_string());
+
+        Self {
+            http_client: reqwest::Client::builder()
+                .timeout(std::time::Duration::from_secs(60))
+                .build()
+                .unwrap_or_else(|_| reqwest::Client::new()),
+            service_url,
+        }
+    }
+}
+
+#[async_trait]
+impl TranslationProvider for HttpTranslationProvider {
+    async fn translate(&self, text: &str, source_lang: &str, target_lang: &str) -> AppResult<String> {
+        let request_data = serde_json::json!({
+            "text": text,
+            "source_lang": source_lang,
+            "target_lang": target_lang,
+        });
+
+        let response = self
+            .http_client
+            .post(&format!("{}/translate", self.service_url))
+            .json(&request_data)
+            .send()
+            .await
+            .map_err(|e| {
+                AppError::with_details(
+                    ErrorCode::InternalError,
+                    "Failed to communicate with translation service",
+                    e.to_string(),
+                )
+            })?;
+
+        if !response.status().is_success() {
+            let error_text = response.text().await.unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(AppError::with_details(
+                ErrorCode::InternalError,
+                "Translation service returned error",
+                error_text,
+            ));
+        }
+
+        let body: serde_json::Value = response.json().await.map_err(|e| {
+            AppError::with_details(
+                ErrorCode::InternalError,
+                "Failed to parse translation response",
+                e.to_string(),
+            )
+        })?;
+
+        Ok(body["translated_text"].as_str().unwrap_or("").to_string())
+    }
+}
+
+pub struct TranslationService {
+    provider: Box<dyn TranslationProvider>,
+}
+
+impl Default for TranslationService {
+    fn default() -> Self {
+        Self {
+            provider: Box::new(HttpTranslationProvider::new()),
+        }
+    }
+}
+
+impl TranslationService {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_provider(provider: Box<dyn TranslationProvider>) -> Self {
+        Self { provider }
+    }
+
+    /// Translates `text` into every language in `target_langs`, chunking the
+    /// text into segments no longer than `max_segment_len` (when set) so
+    /// very long documents don't exceed the backend's request limits. A
+    /// single sentence longer than `max_segment_len` is not split and is sent
+    /// as one over-long segment.
+    pub async fn translate_to_many(
+        &self,
+        text: &str,
+        source_lang: &str,
+        target_langs: &[String],
+        max_segment_len: Option<usize>,
+    ) -> AppResult<HashMap<String, String>> {
+        let segments = match max_segment_len {
+            Some(max_len) => Self::segment_text(text, max_len),
+            None => vec![text.to_string()],
+        };
+
+        let mut translations = HashMap::new();
+
+        for target_lang in target_langs {
+            let mut translated_segments = Vec::with_capacity(segments.len());
+
+            for segment in &segments {
+                let translated = self.provider.translate(segment, source_lang, target_lang).await?;
+                translated_segments.push(translated);
+            }
+
+            translations.insert(target_lang.clone(), translated_segments.join(" "));
+        }
+
+        Ok(translations)
+    }
+
+    /// Splits `text` on sentence/punctuation boundaries (`.`, `!`, `?`),
+    /// packing as many sentences as fit into each segment without exceeding
+    /// `max_len`. A lookahead at each boundary decides whether the next
+    /// sentence still fits before starting a new segment. A sentence that is
+    /// itself longer than `max_len` is not split further; it forms a segment
+    /// of its own that exceeds `max_len`.
+    pub fn segment_text(text: &str, max_len: usize) -> Vec<String> {
+        if text.len() <= max_len {
+            return vec![text.to_string()];
+        }
+
+        let mut segments = Vec::new();
+        let mut current = String::new();
+
+        for sentence in Self::split_into_sentences(text) {
+            if current.is_empty() {
+                current = sentence.to_string();
+                continue;
+            }
+
+            if current.len() + 1 + sentence.len() <= max_len {
+                current.push(' ');
+                current.push_str(sentence);
+            } else {
+                segments.push(std::mem::take(&mut current));
+                current = sentence.to_string();
+            }
+        }
+
+        if !current.is_empty() {
+            segments.push(current);
+        }
+
+        segments
+    }
+
+    fn split_into_sentences(text: &str) -> Vec<&str> {
+        let mut sentences = Vec::new();
+        let mut start = 0;
+
+        for (i, c) in text.char_indices() {
+            if matches!(c, '.' | '!' | '?') {
+                let end = i + c.len_utf8();
+                let sentence = text[start..end].trim();
+                if !sentence.is_empty() {
+                    sentences.push(sentence);
+                }
+                start = end;
+            }
+        }
+
+        let tail = text[start..].trim();
+        if !tail.is_empty() {
+            sentences.push(tail);
+        }
+
+        sentences
+    }
+}
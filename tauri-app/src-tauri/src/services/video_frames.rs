@@ -0,0 +1,338 @@
+use crate::error::{AppError, AppResult, ErrorCode};
+use crate::services::file_handler::VideoFrameExtractionResult;
+use crate::services::ocr::FrameSelectionStrategy;
+use crate::utils::path_utils;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Hamming-distance threshold (out of 64 bits) used by `SceneChange`: a hard
+/// cut differs from the previous frame far more than gradual motion does, so
+/// this sits well above `Deduplicate`'s default threshold.
+const SCENE_CHANGE_HAMMING_THRESHOLD: u32 = 24;
+
+/// Selects which implementation backs `FileHandlerService::extract_frames_from_video`:
+/// the existing Python HTTP service, or an in-process decode via
+/// `ffmpeg-next` that needs no external service running.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FrameExtractionBackend {
+    Python,
+    NativeFfmpeg,
+}
+
+impl Default for FrameExtractionBackend {
+    fn default() -> Self {
+        FrameExtractionBackend::Python
+    }
+}
+
+/// Mirrors the tunables the Python service accepts (see
+/// `call_python_video_frame_service`), so callers get the same knobs
+/// regardless of which backend actually runs.
+#[derive(Debug, Clone)]
+pub struct FrameExtractionOptions {
+    pub frame_interval: u32,
+    pub max_frames: u32,
+    pub selection_strategy: FrameSelectionStrategy,
+    /// Hamming-distance threshold (out of 64 bits) used by `Deduplicate`; a
+    /// candidate frame is kept when it exceeds this distance from the last
+    /// kept frame's hash.
+    pub dedup_hamming_threshold: u32,
+    pub resize_max_width: u32,
+    pub resize_max_height: u32,
+    pub jpeg_quality: u8,
+}
+
+impl Default for FrameExtractionOptions {
+    fn default() -> Self {
+        Self {
+            frame_interval: 30,
+            max_frames: 1000,
+            selection_strategy: FrameSelectionStrategy::default(),
+            dedup_hamming_threshold: 10,
+            resize_max_width: 1920,
+            resize_max_height: 1080,
+            jpeg_quality: 85,
+        }
+    }
+}
+
+/// Decodes `video_path` with `ffmpeg-next`, sampling one candidate frame
+/// every `frame_interval` decoded frames (capped at `max_frames`), applying
+/// `options.selection_strategy` to decide which candidates survive, and
+/// writing the survivors as JPEGs into `output_dir` alongside their source
+/// timestamps.
+pub fn extract_frames_native(
+    video_path: &str,
+    output_dir: &str,
+    options: &FrameExtractionOptions,
+) -> AppResult<VideoFrameExtractionResult> {
+    let start_time = std::time::Instant::now();
+    path_utils::ensure_directory_exists(output_dir)?;
+
+    ffmpeg_next::init().map_err(|e| {
+        AppError::with_details(
+            ErrorCode::InternalError,
+            "Failed to initialize ffmpeg",
+            e.to_string(),
+        )
+    })?;
+
+    let mut input = ffmpeg_next::format::input(&video_path).map_err(|e| {
+        AppError::with_details(
+            ErrorCode::InternalError,
+            "Failed to open video file",
+            e.to_string(),
+        )
+    })?;
+
+    let video_stream = input
+        .streams()
+        .best(ffmpeg_next::media::Type::Video)
+        .ok_or_else(|| AppError::new(ErrorCode::InternalError, "Video has no video stream"))?;
+    let video_stream_index = video_stream.index();
+    let time_base = video_stream.time_base();
+    let stream_params = video_stream.parameters();
+
+    let context = ffmpeg_next::codec::context::Context::from_parameters(stream_params)
+        .map_err(|e| {
+            AppError::with_details(
+                ErrorCode::InternalError,
+                "Failed to read codec parameters",
+                e.to_string(),
+            )
+        })?;
+    let mut decoder = context.decoder().video().map_err(|e| {
+        AppError::with_details(
+            ErrorCode::InternalError,
+            "Failed to open video decoder",
+            e.to_string(),
+        )
+    })?;
+
+    let (resize_width, resize_height) = fit_within(
+        decoder.width(),
+        decoder.height(),
+        options.resize_max_width,
+        options.resize_max_height,
+    );
+
+    let mut output_scaler = ffmpeg_next::software::scaling::Context::get(
+        decoder.format(),
+        decoder.width(),
+        decoder.height(),
+        ffmpeg_next::format::Pixel::RGB24,
+        resize_width,
+        resize_height,
+        ffmpeg_next::software::scaling::Flags::BILINEAR,
+    )
+    .map_err(|e| {
+        AppError::with_details(
+            ErrorCode::InternalError,
+            "Failed to create frame scaler",
+            e.to_string(),
+        )
+    })?;
+
+    // A tiny 8x8 grayscale copy is all the average-hash needs; scaling it
+    // directly off the decoder avoids hashing the (already resized) output
+    // frame and keeps the hash resolution fixed regardless of output size.
+    let mut hash_scaler = ffmpeg_next::software::scaling::Context::get(
+        decoder.format(),
+        decoder.width(),
+        decoder.height(),
+        ffmpeg_next::format::Pixel::GRAY8,
+        8,
+        8,
+        ffmpeg_next::software::scaling::Flags::BILINEAR,
+    )
+    .map_err(|e| {
+        AppError::with_details(
+            ErrorCode::InternalError,
+            "Failed to create hash scaler",
+            e.to_string(),
+        )
+    })?;
+
+    let frame_interval = options.frame_interval.max(1);
+
+    // `FixedInterval` keeps every sampled candidate unconditionally;
+    // `SceneChange`/`Deduplicate` only keep a candidate whose hash differs
+    // from the last kept frame's by more than the given threshold.
+    let dedup_threshold = match options.selection_strategy {
+        FrameSelectionStrategy::FixedInterval => None,
+        FrameSelectionStrategy::SceneChange => Some(SCENE_CHANGE_HAMMING_THRESHOLD),
+        FrameSelectionStrategy::Deduplicate => Some(options.dedup_hamming_threshold),
+    };
+
+    let mut total_video_frames: i32 = 0;
+    let mut frame_paths = Vec::new();
+    let mut frame_timestamps_seconds = Vec::new();
+    let mut last_hash: Option<u64> = None;
+
+    let mut decoded = ffmpeg_next::util::frame::Video::empty();
+    let mut scaled = ffmpeg_next::util::frame::Video::empty();
+    let mut hashed = ffmpeg_next::util::frame::Video::empty();
+
+    'decode: for (stream, packet) in input.packets() {
+        if stream.index() != video_stream_index {
+            continue;
+        }
+
+        decoder.send_packet(&packet).map_err(|e| {
+            AppError::with_details(
+                ErrorCode::InternalError,
+                "Failed to decode video packet",
+                e.to_string(),
+            )
+        })?;
+
+        while decoder.receive_frame(&mut decoded).is_ok() {
+            total_video_frames += 1;
+            if frame_paths.len() as u32 >= options.max_frames {
+                break 'decode;
+            }
+            if (total_video_frames - 1) as u32 % frame_interval != 0 {
+                continue;
+            }
+
+            if let Some(threshold) = dedup_threshold {
+                hash_scaler.run(&decoded, &mut hashed).map_err(|e| {
+                    AppError::with_details(
+                        ErrorCode::InternalError,
+                        "Failed to downscale frame for hashing",
+                        e.to_string(),
+                    )
+                })?;
+                let hash = average_hash(&hashed);
+                let keep = match last_hash {
+                    Some(prev_hash) => hamming_distance(hash, prev_hash) > threshold,
+                    None => true,
+                };
+                if !keep {
+                    continue;
+                }
+                last_hash = Some(hash);
+            }
+
+            output_scaler.run(&decoded, &mut scaled).map_err(|e| {
+                AppError::with_details(
+                    ErrorCode::InternalError,
+                    "Failed to rescale video frame",
+                    e.to_string(),
+                )
+            })?;
+
+            let frame_path = format!("{}/frame_{:06}.jpg", output_dir, frame_paths.len());
+            save_jpeg(&scaled, &frame_path, options.jpeg_quality)?;
+            frame_paths.push(frame_path);
+
+            let timestamp_seconds = decoded
+                .timestamp()
+                .map(|pts| pts as f64 * f64::from(time_base.numerator()) / f64::from(time_base.denominator()))
+                .unwrap_or(0.0);
+            frame_timestamps_seconds.push(timestamp_seconds);
+        }
+    }
+
+    let total_frames_extracted = frame_paths.len() as i32;
+    let mut metadata = HashMap::new();
+    metadata.insert("backend".to_string(), serde_json::json!("native_ffmpeg"));
+
+    Ok(VideoFrameExtractionResult {
+        frame_paths,
+        output_directory: output_dir.to_string(),
+        total_frames_extracted,
+        total_video_frames,
+        processing_time: start_time.elapsed().as_secs_f64(),
+        success: true,
+        error_message: None,
+        metadata,
+        frame_timestamps_seconds,
+    })
+}
+
+/// Scales `(width, height)` down to fit within `(max_width, max_height)`
+/// while preserving aspect ratio; leaves it unchanged if it already fits.
+fn fit_within(width: u32, height: u32, max_width: u32, max_height: u32) -> (u32, u32) {
+    if width <= max_width && height <= max_height {
+        return (width, height);
+    }
+
+    let scale = (max_width as f32 / width as f32).min(max_height as f32 / height as f32);
+    (
+        ((width as f32 * scale) as u32).max(1),
+        ((height as f32 * scale) as u32).max(1),
+    )
+}
+
+/// Computes a 64-bit average hash from an 8x8 grayscale frame: each pixel
+/// contributes one bit, set when it's at or above the frame's mean
+/// brightness.
+fn average_hash(frame: &ffmpeg_next::util::frame::Video) -> u64 {
+    let data = frame.data(0);
+    let stride = frame.stride(0);
+
+    let mut pixels = [0u8; 64];
+    for y in 0..8 {
+        let row_start = y * stride;
+        pixels[y * 8..y * 8 + 8].copy_from_slice(&data[row_start..row_start + 8]);
+    }
+
+    let mean = pixels.iter().map(|&p| p as u32).sum::<u32>() / 64;
+
+    let mut hash = 0u64;
+    for (i, &pixel) in pixels.iter().enumerate() {
+        if pixel as u32 >= mean {
+            hash |= 1 << i;
+        }
+    }
+    hash
+}
+
+fn hamming_distance(a: u64, b: u64) -> u32 {
+    (a ^ b).count_ones()
+}
+
+/// Encodes an RGB24 ffmpeg frame as a JPEG at `quality` and writes it to
+/// `path`.
+fn save_jpeg(frame: &ffmpeg_next::util::frame::Video, path: &str, quality: u8) -> AppResult<()> {
+    let width = frame.width();
+    let height = frame.height();
+    let stride = frame.stride(0);
+    let data = frame.data(0);
+
+    let mut buffer = Vec::with_capacity((width * height * 3) as usize);
+    for y in 0..height as usize {
+        let row_start = y * stride;
+        buffer.extend_from_slice(&data[row_start..row_start + width as usize * 3]);
+    }
+
+    let image_buffer = image::RgbImage::from_raw(width, height, buffer).ok_or_else(|| {
+        AppError::new(
+            ErrorCode::InternalError,
+            "Failed to build frame image buffer",
+        )
+    })?;
+
+    let mut encoded = Vec::new();
+    let mut encoder = image::codecs::jpeg::JpegEncoder::new_with_quality(&mut encoded, quality);
+    encoder
+        .encode_image(&image::DynamicImage::ImageRgb8(image_buffer))
+        .map_err(|e| {
+            AppError::with_details(
+                ErrorCode::InternalError,
+                "Failed to encode JPEG frame",
+                e.to_string(),
+            )
+        })?;
+
+    std::fs::write(path, encoded).map_err(|e| {
+        AppError::with_details(
+            ErrorCode::FileAccess,
+            "Failed to write frame to disk",
+            e.to_string(),
+        )
+    })?;
+
+    Ok(())
+}
@@ -23,11 +23,29 @@ pub mod file_extensions {
 
         pub const PDF_EXTENSIONS: &'static [&'static str] = &["pdf"];
 
+        pub const ARCHIVE_EXTENSIONS: &'static [&'static str] = &["zip", "tar", "tar.gz", "tgz"];
+
         /// Check if a file has a supported image extension
         pub fn is_image(file_path: &str) -> bool {
             Self::has_extension(file_path, Self::IMAGE_EXTENSIONS)
         }
 
+        /// Check if a file has a supported archive extension. Checks the
+        /// full file name rather than just the last extension, since
+        /// `tar.gz` spans two dot-separated components that `Path::extension`
+        /// alone wouldn't capture.
+        pub fn is_archive(file_path: &str) -> bool {
+            let name = Path::new(file_path)
+                .file_name()
+                .and_then(|n| n.to_str())
+                .unwrap_or("")
+                .to_lowercase();
+
+            Self::ARCHIVE_EXTENSIONS
+                .iter()
+                .any(|ext| name.ends_with(&format!(".{}", ext)))
+        }
+
         /// Check if a file has a supported video extension
         pub fn is_video(file_path: &str) -> bool {
             Self::has_extension(file_path, Self::VIDEO_EXTENSIONS)
@@ -82,6 +100,122 @@ pub mod file_validation {
     use super::*;
     use std::fs;
 
+    /// Magic-byte signature a file's content was sniffed as, independent of
+    /// its extension — used to catch files renamed to spoof a different
+    /// format (e.g. a `.exe` passed off as a `.png`).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum DetectedFormat {
+        Png,
+        Jpeg,
+        Bmp,
+        Gif,
+        WebP,
+        Tiff,
+        Pdf,
+        Mp4OrMov,
+    }
+
+    impl DetectedFormat {
+        /// Extensions this format is expected to show up under; used by
+        /// `validate_format_matches_extension`.
+        fn matches_extension(self, extension: &str) -> bool {
+            match self {
+                DetectedFormat::Png => extension == "png",
+                DetectedFormat::Jpeg => matches!(extension, "jpg" | "jpeg"),
+                DetectedFormat::Bmp => extension == "bmp",
+                DetectedFormat::Gif => extension == "gif",
+                DetectedFormat::WebP => extension == "webp",
+                DetectedFormat::Tiff => matches!(extension, "tif" | "tiff"),
+                DetectedFormat::Pdf => extension == "pdf",
+                DetectedFormat::Mp4OrMov => matches!(extension, "mp4" | "mov" | "m4v" | "3gp"),
+            }
+        }
+    }
+
+    /// Reads up to `buf.len()` bytes from the start of `file_path`, returning
+    /// the number actually read (short for files smaller than `buf`).
+    pub fn read_header_bytes(file_path: &str, buf: &mut [u8]) -> std::io::Result<usize> {
+        use std::io::Read;
+        let mut file = fs::File::open(file_path)?;
+        let mut total = 0;
+        while total < buf.len() {
+            match file.read(&mut buf[total..])? {
+                0 => break,
+                n => total += n,
+            }
+        }
+        Ok(total)
+    }
+
+    /// Sniffs `file_path`'s content against known magic-byte signatures,
+    /// independent of its extension. Returns `None` when the header doesn't
+    /// match any format this function recognizes.
+    pub fn detect_format(file_path: &str) -> AppResult<Option<DetectedFormat>> {
+        let mut header = [0u8; 16];
+        let bytes_read = read_header_bytes(file_path, &mut header)?;
+        let header = &header[..bytes_read];
+
+        let format = if header.starts_with(&[0x89, 0x50, 0x4E, 0x47]) {
+            Some(DetectedFormat::Png)
+        } else if header.starts_with(&[0xFF, 0xD8, 0xFF]) {
+            Some(DetectedFormat::Jpeg)
+        } else if header.starts_with(b"BM") {
+            Some(DetectedFormat::Bmp)
+        } else if header.starts_with(b"GIF8") {
+            Some(DetectedFormat::Gif)
+        } else if header.len() >= 12 && &header[0..4] == b"RIFF" && &header[8..12] == b"WEBP" {
+            Some(DetectedFormat::WebP)
+        } else if header.starts_with(&[0x49, 0x49, 0x2A, 0x00])
+            || header.starts_with(&[0x4D, 0x4D, 0x00, 0x2A])
+        {
+            Some(DetectedFormat::Tiff)
+        } else if header.starts_with(b"%PDF") {
+            Some(DetectedFormat::Pdf)
+        } else if header.len() >= 8 && &header[4..8] == b"ftyp" {
+            Some(DetectedFormat::Mp4OrMov)
+        } else {
+            None
+        };
+
+        Ok(format)
+    }
+
+    /// Errors with `ErrorCode::InvalidFileFormat` when the sniffed content
+    /// type disagrees with `file_path`'s extension, or when a file with an
+    /// image extension doesn't match any known signature (every image
+    /// extension has one). For other extensions, content that matches no
+    /// known signature is let through unchanged, since `detect_format` only
+    /// recognizes a subset of supported formats (document formats aren't
+    /// sniffed at all).
+    pub fn validate_format_matches_extension(file_path: &str) -> AppResult<()> {
+        let extension = Path::new(file_path)
+            .extension()
+            .and_then(|e| e.to_str())
+            .unwrap_or("")
+            .to_lowercase();
+
+        if let Some(detected) = detect_format(file_path)? {
+            if !detected.matches_extension(&extension) {
+                return Err(AppError::with_details(
+                    ErrorCode::InvalidFileFormat,
+                    format!("File content does not match its extension: {}", file_path),
+                    format!(
+                        "Detected {:?} content but extension is '.{}'",
+                        detected, extension
+                    ),
+                ));
+            }
+        } else if file_extensions::SupportedExtensions::IMAGE_EXTENSIONS.contains(&extension.as_str()) {
+            return Err(AppError::with_details(
+                ErrorCode::InvalidFileFormat,
+                format!("File content does not match its extension: {}", file_path),
+                format!("No known image signature found for a '.{}' file", extension),
+            ));
+        }
+
+        Ok(())
+    }
+
     /// Validate that a file path exists and is accessible
     pub fn validate_file_path(file_path: &str) -> AppResult<()> {
         let path = Path::new(file_path);